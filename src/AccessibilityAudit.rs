@@ -0,0 +1,262 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Audits a HTML DOM for common accessibility problems, using `AriaRole` to understand `role` attributes.
+/// Use `with_rule()` to turn individual rules off; by default, all rules in `AccessibilityRule::ALL` are checked.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AccessibilityAudit
+{
+	rules: Vec<AccessibilityRule>,
+}
+
+impl Default for AccessibilityAudit
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			rules: AccessibilityRule::ALL.to_vec(),
+		}
+	}
+}
+
+impl AccessibilityAudit
+{
+	/// Check, or do not check, `rule`.
+	#[inline(always)]
+	pub fn with_rule(mut self, rule: AccessibilityRule, check: bool) -> Self
+	{
+		self.rules.retain(|existing| *existing != rule);
+		if check
+		{
+			self.rules.push(rule);
+		}
+		self
+	}
+	
+	/// Audits a HTML DOM, returning every finding in document order.
+	/// Findings are located by path; use `audit_with_source_locations()` to also locate them by line.
+	#[inline(always)]
+	pub fn audit(&self, rc_dom: &RcDom) -> Vec<AccessibilityFinding>
+	{
+		self.audit_optionally_with_source_locations(rc_dom, None)
+	}
+	
+	/// Audits a HTML DOM parsed with one of the `RcDomExt` methods ending in `_with_source_locations`, returning every finding in document order.
+	#[inline(always)]
+	pub fn audit_with_source_locations(&self, rc_dom: &RcDom, source_locations: &SourceLocations) -> Vec<AccessibilityFinding>
+	{
+		self.audit_optionally_with_source_locations(rc_dom, Some(source_locations))
+	}
+	
+	fn audit_optionally_with_source_locations(&self, rc_dom: &RcDom, source_locations: Option<&SourceLocations>) -> Vec<AccessibilityFinding>
+	{
+		let mut labelled_identifiers = Vec::new();
+		Self::find_labelled_identifiers(&rc_dom.document, &mut labelled_identifiers);
+		
+		let mut ancestors = Vec::new();
+		let mut previous_heading_level = None;
+		let mut findings = Vec::new();
+		for child in rc_dom.document.children.borrow().iter()
+		{
+			self.audit_node(child, &mut ancestors, &labelled_identifiers, &mut previous_heading_level, source_locations, &mut findings);
+		}
+		findings
+	}
+	
+	fn audit_node(&self, node: &Rc<Node>, ancestors: &mut Vec<Rc<Node>>, labelled_identifiers: &[String], previous_heading_level: &mut Option<u8>, source_locations: Option<&SourceLocations>, findings: &mut Vec<AccessibilityFinding>)
+	{
+		use self::AccessibilityRule::*;
+		
+		let name = match node.data
+		{
+			NodeData::Element { ref name, .. } if name.is_unprefixed_and_html_namespace_or_none() => name,
+			
+			_ => return,
+		};
+		
+		{
+			let mut finding = |rule: AccessibilityRule, message: String|
+			{
+				if self.rules.contains(&rule)
+				{
+					findings.push
+					(
+						AccessibilityFinding
+						{
+							rule,
+							node: node.clone(),
+							path: local_names_path(node, ancestors),
+							source_location: source_locations.and_then(|source_locations| source_locations.get(node)),
+							message,
+						}
+					)
+				}
+			};
+			
+			let has_non_empty_attribute = |attribute_name: &str| node.get_attribute(attribute_name).map(|value| !value.trim().is_empty()).unwrap_or(false);
+			
+			let role_tokens: Vec<String> = node.get_attribute("role").map(|role| role.split_whitespace().map(|token| token.to_ascii_lowercase()).collect()).unwrap_or_default();
+			let mut explicit_role = None;
+			for token in role_tokens.iter()
+			{
+				match token.parse::<AriaRole>()
+				{
+					Ok(role) => if role.is_abstract()
+					{
+						finding(AbstractRole, format!("role '{}' is an abstract role and must not be used in content", token))
+					}
+					else if explicit_role.is_none()
+					{
+						explicit_role = Some(role)
+					},
+					
+					Err(unknown_aria_role) => finding(ValidRole, unknown_aria_role.to_string()),
+				}
+			}
+			
+			let implicit_role = implicit_role(node);
+			if let Some(explicit_role) = explicit_role
+			{
+				if Some(explicit_role) == implicit_role
+				{
+					finding(RedundantRole, format!("role '{}' is the implicit role of <{}>", explicit_role, name.local));
+				}
+			}
+			let role = explicit_role.or(implicit_role);
+			
+			let is_presentational = match role
+			{
+				Some(AriaRole::presentation) | Some(AriaRole::none) => true,
+				_ => false,
+			};
+			let has_accessible_name_from_attributes = has_non_empty_attribute("aria-label") || has_non_empty_attribute("aria-labelledby") || has_non_empty_attribute("title");
+			
+			match name.local
+			{
+				local_name!("html") => if !has_non_empty_attribute("lang")
+				{
+					finding(HtmlHasLang, "the html element has no lang attribute".to_owned())
+				},
+				
+				local_name!("img") => if !node.has_attribute("alt") && !has_accessible_name_from_attributes && !is_presentational
+				{
+					finding(ImageAlt, "image has no alt attribute".to_owned())
+				},
+				
+				local_name!("input") | local_name!("select") | local_name!("textarea") =>
+				{
+					let type_ = node.get_attribute("type").map(|type_| type_.to_ascii_lowercase());
+					let type_ = type_.as_ref().map(|type_| &type_[..]);
+					
+					if name.local == local_name!("input") && type_ == Some("image")
+					{
+						if !has_non_empty_attribute("alt") && !has_accessible_name_from_attributes
+						{
+							finding(ImageAlt, "image button has no alt attribute".to_owned())
+						}
+					}
+					else
+					{
+						let needs_label = match type_
+						{
+							Some("hidden") | Some("submit") | Some("reset") | Some("button") => name.local != local_name!("input"),
+							_ => true,
+						};
+						let is_labelled = has_accessible_name_from_attributes || node.get_attribute("id").map(|id| labelled_identifiers.contains(&id)).unwrap_or(false) || ancestors.iter().any(|ancestor| ancestor.is_only_local(&local_name!("label")));
+						if needs_label && !is_labelled
+						{
+							finding(Label, format!("form control <{}> has no label", name.local))
+						}
+					}
+				}
+				
+				_ => (),
+			}
+			
+			let heading_level = match name.local
+			{
+				local_name!("h1") => Some(1),
+				local_name!("h2") => Some(2),
+				local_name!("h3") => Some(3),
+				local_name!("h4") => Some(4),
+				local_name!("h5") => Some(5),
+				local_name!("h6") => Some(6),
+				_ => if explicit_role == Some(AriaRole::heading)
+				{
+					Some(node.get_attribute("aria-level").and_then(|level| level.trim().parse().ok()).unwrap_or(2))
+				}
+				else
+				{
+					None
+				},
+			};
+			if let Some(heading_level) = heading_level
+			{
+				if let Some(previous_heading_level) = *previous_heading_level
+				{
+					if heading_level > previous_heading_level + 1
+					{
+						finding(HeadingOrder, format!("heading level {} follows heading level {}", heading_level, previous_heading_level));
+					}
+				}
+				*previous_heading_level = Some(heading_level);
+			}
+			
+			let aria_attribute_names: Vec<String> = match node.data
+			{
+				NodeData::Element { ref attrs, .. } => attrs.borrow().iter().filter(|attribute| attribute.name.ns == ns!() && attribute.name.local.starts_with("aria-")).map(|attribute| attribute.name.local.to_string()).collect(),
+				_ => unreachable!("node is an element"),
+			};
+			for aria_attribute_name in aria_attribute_names.iter()
+			{
+				let aria_attribute_name = &aria_attribute_name[..];
+				if !AriaRole::is_state_or_property(aria_attribute_name)
+				{
+					finding(ValidAttribute, format!("'{}' is not an ARIA state or property", aria_attribute_name));
+					continue;
+				}
+				
+				let is_supported = match role
+				{
+					None => AriaRole::GLOBAL_STATES_AND_PROPERTIES.contains(&aria_attribute_name),
+					Some(role) => role.supports_state_or_property(aria_attribute_name),
+				};
+				if !is_supported
+				{
+					let role_description = match role
+					{
+						None => "no role".to_owned(),
+						Some(role) => format!("role '{}'", role),
+					};
+					finding(AllowedAttribute, format!("'{}' is not supported by {}", aria_attribute_name, role_description));
+				}
+			}
+		}
+		
+		ancestors.push(node.clone());
+		for child in node.children.borrow().iter()
+		{
+			self.audit_node(child, ancestors, labelled_identifiers, previous_heading_level, source_locations, findings);
+		}
+		ancestors.pop();
+	}
+	
+	fn find_labelled_identifiers(node: &Rc<Node>, labelled_identifiers: &mut Vec<String>)
+	{
+		if node.is_only_local(&local_name!("label"))
+		{
+			if let Some(identifier) = node.get_attribute("for")
+			{
+				labelled_identifiers.push(identifier);
+			}
+		}
+		
+		for child in node.children.borrow().iter()
+		{
+			Self::find_labelled_identifiers(child, labelled_identifiers);
+		}
+	}
+}
@@ -0,0 +1,45 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An accessibility problem found by `AccessibilityAudit`.
+#[derive(Clone)]
+pub struct AccessibilityFinding
+{
+	/// The rule that was broken.
+	pub rule: AccessibilityRule,
+	
+	/// The node that broke it.
+	pub node: Rc<Node>,
+	
+	/// The local names of the node's ancestors and itself, eg `html > body > img`.
+	pub path: String,
+	
+	/// Where in the HTML source the node started, if the DOM was parsed with source locations.
+	pub source_location: Option<SourceLocation>,
+	
+	/// A description of the problem.
+	pub message: String,
+}
+
+impl Debug for AccessibilityFinding
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("AccessibilityFinding").field("rule", &self.rule).field("path", &self.path).field("source_location", &self.source_location).field("message", &self.message).finish()
+	}
+}
+
+impl Display for AccessibilityFinding
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		if let Some(source_location) = self.source_location
+		{
+			write!(f, "{}: ", source_location)?;
+		}
+		write!(f, "{}: {} [{}]", self.path, self.message, self.rule)
+	}
+}
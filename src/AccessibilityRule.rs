@@ -0,0 +1,90 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A rule checked by `AccessibilityAudit`.
+/// Each rule has an identifier, eg `image-alt`, which matches that used by common accessibility tools where there is one.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AccessibilityRule
+{
+	/// `image-alt`: images must have alternative text (`alt`, `aria-label`, `aria-labelledby` or `title`) unless they are presentational.
+	ImageAlt,
+	
+	/// `label`: form controls must have a label (`<label for>`, an ancestor `<label>`, `aria-label`, `aria-labelledby` or `title`).
+	Label,
+	
+	/// `aria-valid-role`: `role` values must be known ARIA roles.
+	ValidRole,
+	
+	/// `aria-abstract-role`: `role` values must not be abstract ARIA roles, such as `widget`.
+	AbstractRole,
+	
+	/// `aria-redundant-role`: `role` values should not duplicate an element's implicit role, eg `<button role="button">`.
+	RedundantRole,
+	
+	/// `html-has-lang`: the `<html>` element must have a `lang` attribute.
+	HtmlHasLang,
+	
+	/// `heading-order`: heading levels should only increase by one, eg `<h2>` should not follow `<h1>`... `<h4>`.
+	HeadingOrder,
+	
+	/// `aria-valid-attr`: `aria-*` attributes must be known ARIA states and properties.
+	ValidAttribute,
+	
+	/// `aria-allowed-attr`: `aria-*` attributes must be supported by, and not prohibited for, the element's role.
+	AllowedAttribute,
+}
+
+impl Display for AccessibilityRule
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.identifier())
+	}
+}
+
+impl AccessibilityRule
+{
+	/// All rules.
+	pub const ALL: [AccessibilityRule; 9] =
+	[
+		AccessibilityRule::ImageAlt,
+		AccessibilityRule::Label,
+		AccessibilityRule::ValidRole,
+		AccessibilityRule::AbstractRole,
+		AccessibilityRule::RedundantRole,
+		AccessibilityRule::HtmlHasLang,
+		AccessibilityRule::HeadingOrder,
+		AccessibilityRule::ValidAttribute,
+		AccessibilityRule::AllowedAttribute,
+	];
+	
+	/// The rule's identifier, eg `image-alt`.
+	#[inline(always)]
+	pub fn identifier(&self) -> &'static str
+	{
+		use self::AccessibilityRule::*;
+		
+		match *self
+		{
+			ImageAlt => "image-alt",
+			
+			Label => "label",
+			
+			ValidRole => "aria-valid-role",
+			
+			AbstractRole => "aria-abstract-role",
+			
+			RedundantRole => "aria-redundant-role",
+			
+			HtmlHasLang => "html-has-lang",
+			
+			HeadingOrder => "heading-order",
+			
+			ValidAttribute => "aria-valid-attr",
+			
+			AllowedAttribute => "aria-allowed-attr",
+		}
+	}
+}
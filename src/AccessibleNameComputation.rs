@@ -0,0 +1,452 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// An implementation of the W3C [Accessible Name and Description Computation 1.2](https://www.w3.org/TR/accname-1.2/) for HTML, using the [HTML-AAM](https://www.w3.org/TR/html-aam-1.0/#accessible-name-and-description-computation) rules for host language labels.
+// CSS generated content and the `display` of elements other than via a `style` attribute are not known; elements that are not phrasing content are treated as blocks.
+struct AccessibleNameComputation
+{
+	root: Rc<Node>,
+	visited: Vec<*const Node>,
+}
+
+impl AccessibleNameComputation
+{
+	fn name(node: &Rc<Node>) -> String
+	{
+		if Self::is_hidden_including_ancestors(node)
+		{
+			return String::new();
+		}
+		
+		let mut computation = Self::new(node);
+		let name = computation.text_alternative(node, AccessibleNameTraversal::default());
+		Self::collapse_whitespace(&name)
+	}
+	
+	fn description(node: &Rc<Node>) -> String
+	{
+		if Self::is_hidden_including_ancestors(node)
+		{
+			return String::new();
+		}
+		
+		let mut computation = Self::new(node);
+		computation.visited.push(node.as_ref() as *const Node);
+		if let Some(description) = computation.referenced_text_alternatives(node, "aria-describedby", AccessibleNameTraversal::default())
+		{
+			return Self::collapse_whitespace(&description);
+		}
+		
+		match Self::non_empty_attribute(node, "title")
+		{
+			Some(ref title) if Self::collapse_whitespace(title) != Self::name(node) => Self::collapse_whitespace(title),
+			
+			_ => String::new(),
+		}
+	}
+	
+	#[inline(always)]
+	fn new(node: &Rc<Node>) -> Self
+	{
+		let mut root = node.clone();
+		while let Some(parent) = root.parent()
+		{
+			root = parent;
+		}
+		
+		Self
+		{
+			root,
+			visited: Vec::new(),
+		}
+	}
+	
+	// Step 2 of the computation for one node.
+	fn text_alternative(&mut self, node: &Rc<Node>, traversal: AccessibleNameTraversal) -> String
+	{
+		match node.data
+		{
+			NodeData::Element { .. } => (),
+			
+			Text { ref contents } => return contents.borrow().to_string(),
+			
+			_ => return String::new(),
+		}
+		
+		// 2A
+		if !traversal.include_hidden && Self::is_hidden(node)
+		{
+			return String::new();
+		}
+		
+		let pointer = node.as_ref() as *const Node;
+		if self.visited.contains(&pointer)
+		{
+			return String::new();
+		}
+		self.visited.push(pointer);
+		
+		// 2B
+		if !traversal.in_labelledby
+		{
+			if let Some(name) = self.referenced_text_alternatives(node, "aria-labelledby", traversal)
+			{
+				return name;
+			}
+		}
+		
+		let role = Self::role(node);
+		
+		// 2C
+		if traversal.in_reference || traversal.in_recursion
+		{
+			if let Some(value) = self.embedded_control_value(node, role)
+			{
+				return value;
+			}
+		}
+		
+		// 2D
+		if let Some(label) = Self::non_empty_attribute(node, "aria-label")
+		{
+			return label;
+		}
+		
+		// 2E
+		match role
+		{
+			Some(AriaRole::presentation) | Some(AriaRole::none) => (),
+			
+			_ => if let Some(label) = self.host_language_label(node, traversal)
+			{
+				return label;
+			},
+		}
+		
+		// 2F
+		let is_named_from_content = role.map(|role| role.is_named_from_content()).unwrap_or(false);
+		if is_named_from_content || traversal.in_reference || traversal.in_recursion
+		{
+			let content = self.content(node, traversal);
+			if !content.trim().is_empty()
+			{
+				return content;
+			}
+		}
+		
+		// 2I
+		if let Some(title) = Self::non_empty_attribute(node, "title")
+		{
+			return title;
+		}
+		
+		if node.is_only_local_of(&[local_name!("input"), local_name!("textarea")])
+		{
+			if let Some(placeholder) = Self::non_empty_attribute(node, "placeholder")
+			{
+				return placeholder;
+			}
+		}
+		
+		String::new()
+	}
+	
+	// The text alternatives of the elements referenced by an IDREFS attribute, such as `aria-labelledby`, or None if no element is referenced.
+	fn referenced_text_alternatives(&mut self, node: &Rc<Node>, attribute_name: &str, traversal: AccessibleNameTraversal) -> Option<String>
+	{
+		let identifiers = Self::non_empty_attribute(node, attribute_name)?;
+		let referenced_nodes: Vec<Rc<Node>> = identifiers.split_whitespace().filter_map(|identifier| self.find_element_by_identifier(identifier)).collect();
+		if referenced_nodes.is_empty()
+		{
+			return None;
+		}
+		
+		let by_labelledby = attribute_name == "aria-labelledby";
+		let text_alternatives: Vec<String> = referenced_nodes.iter().map(|referenced_node|
+		{
+			let include_hidden = Self::is_hidden_including_ancestors(referenced_node);
+			self.text_alternative(referenced_node, traversal.referenced(by_labelledby, include_hidden))
+		}).collect();
+		Some(text_alternatives.join(" "))
+	}
+	
+	// 2C: the value of a control embedded within the label of another, such as a `<select>` inside a `<label>`.
+	fn embedded_control_value(&mut self, node: &Rc<Node>, role: Option<AriaRole>) -> Option<String>
+	{
+		use self::AriaRole::*;
+		
+		let role = role?;
+		if role.is_a(textbox)
+		{
+			if node.is_only_local(&local_name!("textarea"))
+			{
+				Some(self.content(node, AccessibleNameTraversal::default()))
+			}
+			else
+			{
+				Some(node.get_attribute("value").unwrap_or_default())
+			}
+		}
+		else if role == combobox || role == listbox
+		{
+			let mut selected_options = Vec::new();
+			Self::find_selected_options(node, &mut selected_options);
+			let text_alternatives: Vec<String> = selected_options.iter().map(|selected_option| self.content(selected_option, AccessibleNameTraversal::default())).collect();
+			Some(text_alternatives.join(" "))
+		}
+		else if role.is_a(range)
+		{
+			Self::non_empty_attribute(node, "aria-valuetext").or_else(|| Self::non_empty_attribute(node, "aria-valuenow")).or_else(|| node.get_attribute("value")).or(Some(String::new()))
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	// 2E: labels from HTML, such as `<label>`, `alt` and `<legend>`.
+	//noinspection SpellCheckingInspection
+	fn host_language_label(&mut self, node: &Rc<Node>, traversal: AccessibleNameTraversal) -> Option<String>
+	{
+		let local_name = match node.data
+		{
+			NodeData::Element { ref name, .. } if name.is_unprefixed_and_html_namespace_or_none() => name.local.clone(),
+			
+			_ => return None,
+		};
+		
+		let non_empty = |label: String| if label.trim().is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(label)
+		};
+		
+		match local_name
+		{
+			local_name!("input") => match node.get_attribute("type").map(|type_| type_.trim().to_ascii_lowercase()).as_ref().map(|type_| &type_[..]).unwrap_or("text")
+			{
+				"hidden" => None,
+				
+				type_ @ "button" | type_ @ "reset" | type_ @ "submit" => Self::non_empty_attribute(node, "value").or_else(|| match type_
+				{
+					"reset" => Some("Reset".to_owned()),
+					"submit" => Some("Submit".to_owned()),
+					_ => None,
+				}),
+				
+				"image" => Self::non_empty_attribute(node, "alt").or_else(|| Self::non_empty_attribute(node, "value")).or_else(|| Some("Submit".to_owned())),
+				
+				_ => non_empty(self.labels(node, traversal)),
+			},
+			
+			local_name!("button") | local_name!("meter") | local_name!("output") | local_name!("progress") | local_name!("select") | local_name!("textarea") => non_empty(self.labels(node, traversal)),
+			
+			local_name!("img") | local_name!("area") => Self::non_empty_attribute(node, "alt"),
+			
+			local_name!("fieldset") => self.first_child_element_content(node, local_name!("legend"), traversal),
+			
+			local_name!("figure") => self.first_child_element_content(node, local_name!("figcaption"), traversal),
+			
+			local_name!("table") => self.first_child_element_content(node, local_name!("caption"), traversal),
+			
+			_ => None,
+		}
+	}
+	
+	// The text alternatives of the `<label>` elements of a labelable element, both ancestors and those whose `for` attribute is its `id`.
+	fn labels(&mut self, node: &Rc<Node>, traversal: AccessibleNameTraversal) -> String
+	{
+		let mut labels = Vec::new();
+		
+		let mut ancestor = ElementNode::new(node.clone()).parent_element();
+		while let Some(element) = ancestor
+		{
+			if element.is_only_local(&local_name!("label"))
+			{
+				labels.push(element.node.clone());
+				break;
+			}
+			ancestor = element.parent_element();
+		}
+		
+		if let Some(identifier) = Self::non_empty_attribute(node, "id")
+		{
+			let root = ElementNode::new(self.root.clone());
+			Self::find_elements(&root, &mut |element| element.is_only_local(&local_name!("label")) && element.use_attribute_value(&local_name!("for"), |for_| for_ == identifier, false), &mut labels);
+		}
+		
+		let text_alternatives: Vec<String> = labels.iter().map(|label| self.text_alternative(label, traversal.referenced(false, false))).collect();
+		text_alternatives.join(" ")
+	}
+	
+	#[inline(always)]
+	fn first_child_element_content(&mut self, node: &Rc<Node>, local_name: LocalName, traversal: AccessibleNameTraversal) -> Option<String>
+	{
+		let mut child = ElementNode::new(node.clone()).first_child_element();
+		while let Some(element) = child
+		{
+			if element.is_only_local(&local_name)
+			{
+				let content = self.text_alternative(&element.node, traversal.referenced(false, false));
+				return if content.trim().is_empty()
+				{
+					None
+				}
+				else
+				{
+					Some(content)
+				}
+			}
+			child = element.next_sibling_element();
+		}
+		None
+	}
+	
+	// 2F: the text alternatives of child nodes; those of elements that are not phrasing content are separated by spaces, as they are usually blocks.
+	fn content(&mut self, node: &Rc<Node>, traversal: AccessibleNameTraversal) -> String
+	{
+		let mut content = String::new();
+		for child in node.children.borrow().iter()
+		{
+			let text_alternative = self.text_alternative(child, traversal.recursion());
+			match child.data
+			{
+				NodeData::Element { .. } if !ContentCategory::Phrasing.includes(child) =>
+				{
+					content.push(' ');
+					content.push_str(&text_alternative);
+					content.push(' ');
+				}
+				
+				_ => content.push_str(&text_alternative),
+			}
+		}
+		content
+	}
+	
+	fn find_element_by_identifier(&self, identifier: &str) -> Option<Rc<Node>>
+	{
+		let root = ElementNode::new(self.root.clone());
+		let mut found = Vec::new();
+		Self::find_elements(&root, &mut |element| element.use_attribute_value(&local_name!("id"), |id| id == identifier, false), &mut found);
+		found.into_iter().next()
+	}
+	
+	fn find_elements<Predicate: FnMut(&ElementNode) -> bool>(element: &ElementNode, predicate: &mut Predicate, found: &mut Vec<Rc<Node>>)
+	{
+		let mut child = element.first_child_element();
+		while let Some(child_element) = child
+		{
+			if predicate(&child_element)
+			{
+				found.push(child_element.node.clone());
+			}
+			Self::find_elements(&child_element, predicate, found);
+			child = child_element.next_sibling_element();
+		}
+	}
+	
+	fn find_selected_options(node: &Rc<Node>, selected_options: &mut Vec<Rc<Node>>)
+	{
+		let mut child = ElementNode::new(node.clone()).first_child_element();
+		while let Some(element) = child
+		{
+			if element.is_only_local(&local_name!("option")) || element.node.get_attribute("role").map(|role| role.trim().eq_ignore_ascii_case("option")).unwrap_or(false)
+			{
+				let is_selected = element.node.has_attribute("selected") || element.node.get_attribute("aria-selected").map(|selected| selected.trim() == "true").unwrap_or(false);
+				if is_selected
+				{
+					selected_options.push(element.node.clone());
+				}
+			}
+			else
+			{
+				Self::find_selected_options(&element.node, selected_options);
+			}
+			child = element.next_sibling_element();
+		}
+	}
+	
+	// The first valid, non-abstract role in the `role` attribute, or otherwise the implicit role.
+	#[inline(always)]
+	fn role(node: &Rc<Node>) -> Option<AriaRole>
+	{
+		let explicit_role = node.get_attribute("role").and_then(|role| role.split_whitespace().filter_map(|token| token.parse::<AriaRole>().ok()).find(|role| !role.is_abstract()));
+		explicit_role.or_else(|| implicit_role(node))
+	}
+	
+	fn is_hidden_including_ancestors(node: &Rc<Node>) -> bool
+	{
+		let mut element = Some(ElementNode::new(node.clone()));
+		while let Some(current) = element
+		{
+			if Self::is_hidden(&current.node)
+			{
+				return true;
+			}
+			element = current.parent_element();
+		}
+		false
+	}
+	
+	// Is this element not rendered or hidden from the accessibility tree?
+	#[inline(always)]
+	fn is_hidden(node: &Rc<Node>) -> bool
+	{
+		match node.data
+		{
+			NodeData::Element { .. } => (),
+			
+			_ => return false,
+		}
+		
+		if node.is_only_local_of(&[local_name!("head"), local_name!("script"), local_name!("style"), local_name!("template")])
+		{
+			return true;
+		}
+		
+		if node.is_only_local(&local_name!("input")) && node.get_attribute("type").map(|type_| type_.trim().eq_ignore_ascii_case("hidden")).unwrap_or(false)
+		{
+			return true;
+		}
+		
+		if node.has_attribute("hidden") || node.get_attribute("aria-hidden").map(|aria_hidden| aria_hidden.trim().eq_ignore_ascii_case("true")).unwrap_or(false)
+		{
+			return true;
+		}
+		
+		match node.get_attribute("style")
+		{
+			None => false,
+			
+			Some(style) =>
+			{
+				let style: String = style.chars().filter(|character| !character.is_whitespace()).collect::<String>().to_ascii_lowercase();
+				style.contains("display:none") || style.contains("visibility:hidden")
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn non_empty_attribute(node: &Rc<Node>, attribute_name: &str) -> Option<String>
+	{
+		node.get_attribute(attribute_name).and_then(|value| if value.trim().is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(value)
+		})
+	}
+	
+	#[inline(always)]
+	fn collapse_whitespace(text: &str) -> String
+	{
+		text.split_whitespace().collect::<Vec<&str>>().join(" ")
+	}
+}
@@ -0,0 +1,45 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// Where in the accessible name computation a node is being visited.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+struct AccessibleNameTraversal
+{
+	// The node is, or is a descendant of, a node referenced by `aria-labelledby`; `aria-labelledby` is not followed again.
+	in_labelledby: bool,
+	
+	// The node is, or is a descendant of, a node referenced by `aria-labelledby`, `aria-describedby` or a `<label>`; its content is used whatever its role.
+	in_reference: bool,
+	
+	// The node is a descendant of the node whose content is being used.
+	in_recursion: bool,
+	
+	// The node directly referenced was hidden, so hidden descendants are included.
+	include_hidden: bool,
+}
+
+impl AccessibleNameTraversal
+{
+	#[inline(always)]
+	fn referenced(self, by_labelledby: bool, include_hidden: bool) -> Self
+	{
+		Self
+		{
+			in_labelledby: self.in_labelledby || by_labelledby,
+			in_reference: true,
+			in_recursion: false,
+			include_hidden,
+		}
+	}
+	
+	#[inline(always)]
+	fn recursion(self) -> Self
+	{
+		Self
+		{
+			in_recursion: true,
+			.. self
+		}
+	}
+}
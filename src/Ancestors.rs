@@ -0,0 +1,35 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An iterator over the ancestors of a node, from its parent up to and including the root (ordinarily the `Document`); returned by `NodeExt::ancestors()`.
+#[derive(Debug, Clone)]
+pub struct Ancestors
+{
+	next: Option<ElementNode>,
+}
+
+impl Iterator for Ancestors
+{
+	type Item = Rc<Node>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let current = self.next.take()?;
+		self.next = current.parent_element();
+		Some(current.node)
+	}
+}
+
+impl Ancestors
+{
+	#[inline(always)]
+	fn new(node: &Rc<Node>) -> Self
+	{
+		Self
+		{
+			next: ElementNode::new(node.clone()).parent_element(),
+		}
+	}
+}
@@ -3,7 +3,9 @@
 
 
 //noinspection SpellCheckingInspection
-/// Valid values of Aria `role` global attribute
+/// Valid values of Aria `role` global attribute.
+///
+/// This is the complete role taxonomy of [WAI-ARIA 1.2](https://www.w3.org/TR/wai-aria-1.2/), including abstract roles.
 /// See [Aria Roles 101](http://www.webteacher.ws/2010/10/14/aria-roles-101/) for more.
 /// Navigation roles are probably the most useful:-
 /// - application
@@ -197,10 +199,637 @@ pub enum AriaRole
 	
 	/// "search"
 	search,
+	
+	/// "command"
+	/// An abstract role, which must not be used in content.
+	command,
+	
+	/// "composite"
+	/// An abstract role, which must not be used in content.
+	composite,
+	
+	/// "input"
+	/// An abstract role, which must not be used in content.
+	input,
+	
+	/// "landmark"
+	/// An abstract role, which must not be used in content.
+	landmark,
+	
+	/// "range"
+	/// An abstract role, which must not be used in content.
+	range,
+	
+	/// "roletype"
+	/// An abstract role, which must not be used in content.
+	roletype,
+	
+	/// "section"
+	/// An abstract role, which must not be used in content.
+	section,
+	
+	/// "sectionhead"
+	/// An abstract role, which must not be used in content.
+	sectionhead,
+	
+	/// "select"
+	/// An abstract role, which must not be used in content.
+	select,
+	
+	/// "structure"
+	/// An abstract role, which must not be used in content.
+	structure,
+	
+	/// "widget"
+	/// An abstract role, which must not be used in content.
+	widget,
+	
+	/// "window"
+	/// An abstract role, which must not be used in content.
+	window,
+	
+	/// "blockquote"
+	blockquote,
+	
+	/// "caption"
+	caption,
+	
+	/// "cell"
+	cell,
+	
+	/// "code"
+	code,
+	
+	/// "deletion"
+	deletion,
+	
+	/// "emphasis"
+	emphasis,
+	
+	/// "feed"
+	feed,
+	
+	/// "figure"
+	figure,
+	
+	/// "generic"
+	generic,
+	
+	/// "insertion"
+	insertion,
+	
+	/// "meter"
+	meter,
+	
+	/// "none"
+	none,
+	
+	/// "paragraph"
+	paragraph,
+	
+	/// "rowgroup"
+	rowgroup,
+	
+	/// "searchbox"
+	searchbox,
+	
+	/// "strong"
+	strong,
+	
+	/// "subscript"
+	subscript,
+	
+	/// "superscript"
+	superscript,
+	
+	/// "switch"
+	switch,
+	
+	/// "table"
+	table,
+	
+	/// "term"
+	term,
+	
+	/// "time"
+	time,
+}
+
+impl Display for AriaRole
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.to_str())
+	}
+}
+
+impl FromStr for AriaRole
+{
+	type Err = UnknownAriaRole;
+	
+	/// Parses a single token of a `role` attribute; matching is ASCII case-insensitive.
+	//noinspection SpellCheckingInspection
+	fn from_str(role: &str) -> Result<Self, Self::Err>
+	{
+		use self::AriaRole::*;
+		
+		let role = match &role.to_ascii_lowercase()[..]
+		{
+			"alert" => alert,
+			
+			"alertdialog" => alertdialog,
+			
+			"button" => button,
+			
+			"checkbox" => checkbox,
+			
+			"dialog" => dialog,
+			
+			"gridcell" => gridcell,
+			
+			"link" => link,
+			
+			"log" => log,
+			
+			"marquee" => marquee,
+			
+			"menuitem" => menuitem,
+			
+			"menuitemcheckbox" => menuitemcheckbox,
+			
+			"menuitemradio" => menuitemradio,
+			
+			"option" => option,
+			
+			"progressbar" => progressbar,
+			
+			"radio" => radio,
+			
+			"scrollbar" => scrollbar,
+			
+			"slider" => slider,
+			
+			"spinbutton" => spinbutton,
+			
+			"status" => status,
+			
+			"tab" => tab,
+			
+			"tabpanel" => tabpanel,
+			
+			"textbox" => textbox,
+			
+			"timer" => timer,
+			
+			"tooltip" => tooltip,
+			
+			"treeitem" => treeitem,
+			
+			"combobox" => combobox,
+			
+			"grid" => grid,
+			
+			"listbox" => listbox,
+			
+			"menu" => menu,
+			
+			"menubar" => menubar,
+			
+			"radiogroup" => radiogroup,
+			
+			"tablist" => tablist,
+			
+			"tree" => tree,
+			
+			"treegrid" => treegrid,
+			
+			"article" => article,
+			
+			"columnheader" => columnheader,
+			
+			"definition" => definition,
+			
+			"directory" => directory,
+			
+			"document" => document,
+			
+			"group" => group,
+			
+			"heading" => heading,
+			
+			"img" => img,
+			
+			"list" => list,
+			
+			"listitem" => listitem,
+			
+			"math" => math,
+			
+			"note" => note,
+			
+			"presentation" => presentation,
+			
+			"region" => region,
+			
+			"row" => row,
+			
+			"rowheader" => rowheader,
+			
+			"separator" => separator,
+			
+			"toolbar" => toolbar,
+			
+			"application" => application,
+			
+			"banner" => banner,
+			
+			"complementary" => complementary,
+			
+			"contentinfo" => contentinfo,
+			
+			"form" => form,
+			
+			"main" => main,
+			
+			"navigation" => navigation,
+			
+			"search" => search,
+			
+			"command" => command,
+			
+			"composite" => composite,
+			
+			"input" => input,
+			
+			"landmark" => landmark,
+			
+			"range" => range,
+			
+			"roletype" => roletype,
+			
+			"section" => section,
+			
+			"sectionhead" => sectionhead,
+			
+			"select" => select,
+			
+			"structure" => structure,
+			
+			"widget" => widget,
+			
+			"window" => window,
+			
+			"blockquote" => blockquote,
+			
+			"caption" => caption,
+			
+			"cell" => cell,
+			
+			"code" => code,
+			
+			"deletion" => deletion,
+			
+			"emphasis" => emphasis,
+			
+			"feed" => feed,
+			
+			"figure" => figure,
+			
+			"generic" => generic,
+			
+			"insertion" => insertion,
+			
+			"meter" => meter,
+			
+			"none" => none,
+			
+			"paragraph" => paragraph,
+			
+			"rowgroup" => rowgroup,
+			
+			"searchbox" => searchbox,
+			
+			"strong" => strong,
+			
+			"subscript" => subscript,
+			
+			"superscript" => superscript,
+			
+			"switch" => switch,
+			
+			"table" => table,
+			
+			"term" => term,
+			
+			"time" => time,
+			
+			_ => return Err(UnknownAriaRole(role.to_owned())),
+		};
+		Ok(role)
+	}
 }
 
 impl AriaRole
 {
+	/// States and properties supported by all roles.
+	//noinspection SpellCheckingInspection
+	pub const GLOBAL_STATES_AND_PROPERTIES: [&'static str; 21] = ["aria-atomic", "aria-busy", "aria-controls", "aria-current", "aria-describedby", "aria-details", "aria-disabled", "aria-dropeffect", "aria-errormessage", "aria-flowto", "aria-grabbed", "aria-haspopup", "aria-hidden", "aria-invalid", "aria-keyshortcuts", "aria-label", "aria-labelledby", "aria-live", "aria-owns", "aria-relevant", "aria-roledescription"];
+	
+	/// Is `name` (eg `aria-checked`) a WAI-ARIA 1.2 state or property?
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	pub fn is_state_or_property(name: &str) -> bool
+	{
+		match name
+		{
+			"aria-activedescendant" | "aria-autocomplete" | "aria-checked" | "aria-colcount" | "aria-colindex" | "aria-colspan" | "aria-expanded" | "aria-level" | "aria-modal" | "aria-multiline" | "aria-multiselectable" | "aria-orientation" | "aria-placeholder" | "aria-posinset" | "aria-pressed" | "aria-readonly" | "aria-required" | "aria-rowcount" | "aria-rowindex" | "aria-rowspan" | "aria-selected" | "aria-setsize" | "aria-sort" | "aria-valuemax" | "aria-valuemin" | "aria-valuenow" | "aria-valuetext" => true,
+			
+			_ => Self::GLOBAL_STATES_AND_PROPERTIES.contains(&name),
+		}
+	}
+	
+	/// The category of this role in the WAI-ARIA taxonomy.
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	pub fn category(&self) -> AriaRoleCategory
+	{
+		use self::AriaRole::*;
+		use self::AriaRoleCategory::*;
+		
+		match *self
+		{
+			alert | log | marquee | status | timer => LiveRegion,
+			
+			alertdialog | dialog => Window,
+			
+			button | checkbox | gridcell | link | menuitem | menuitemcheckbox | menuitemradio | option | progressbar | radio | scrollbar | slider | spinbutton | tab | tabpanel | textbox | treeitem | searchbox | switch => Widget,
+			
+			tooltip | article | columnheader | definition | directory | document | group | heading | img | list | listitem | math | note | presentation | row | rowheader | separator | toolbar | application | blockquote | caption | cell | code | deletion | emphasis | feed | figure | generic | insertion | meter | none | paragraph | rowgroup | strong | subscript | superscript | table | term | time => DocumentStructure,
+			
+			combobox | grid | listbox | menu | menubar | radiogroup | tablist | tree | treegrid => Composite,
+			
+			region | banner | complementary | contentinfo | form | main | navigation | search => Landmark,
+			
+			command | composite | input | landmark | range | roletype | section | sectionhead | select | structure | widget | window => Abstract,
+		}
+	}
+	
+	/// Is this an abstract role, which must not be used in content?
+	#[inline(always)]
+	pub fn is_abstract(&self) -> bool
+	{
+		self.category() == AriaRoleCategory::Abstract
+	}
+	
+	/// The immediate superclasses of this role; only `roletype` has none.
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	pub fn superclasses(&self) -> &'static [AriaRole]
+	{
+		use self::AriaRole::*;
+		
+		match *self
+		{
+			alert | log | marquee | status | tabpanel | tooltip | definition | group | img | list | listitem | math | note | landmark | blockquote | caption | cell | code | deletion | emphasis | figure | insertion | paragraph | strong | subscript | superscript | table | term | time => &[section],
+			
+			alertdialog => &[alert, dialog],
+			
+			button | link | menuitem => &[command],
+			
+			checkbox | option | radio | textbox | combobox => &[input],
+			
+			dialog => &[window],
+			
+			gridcell => &[cell, widget],
+			
+			menuitemcheckbox => &[menuitem],
+			
+			menuitemradio => &[menuitemcheckbox],
+			
+			progressbar | scrollbar => &[range, widget],
+			
+			slider => &[input, range],
+			
+			spinbutton => &[composite, input, range],
+			
+			tab => &[sectionhead, widget],
+			
+			timer => &[status],
+			
+			treeitem => &[listitem, option],
+			
+			grid => &[composite, table],
+			
+			listbox | menu | radiogroup | tree => &[select],
+			
+			menubar => &[menu],
+			
+			tablist => &[composite],
+			
+			treegrid => &[grid, tree],
+			
+			article => &[document],
+			
+			columnheader | rowheader => &[cell, gridcell, sectionhead],
+			
+			directory | feed => &[list],
+			
+			document | presentation | application | range | section | sectionhead | generic | none | rowgroup => &[structure],
+			
+			heading => &[sectionhead],
+			
+			region | banner | complementary | contentinfo | form | main | navigation | search => &[landmark],
+			
+			row => &[group, widget],
+			
+			separator => &[structure, widget],
+			
+			toolbar => &[group],
+			
+			command | composite | input => &[widget],
+			
+			roletype => &[],
+			
+			select => &[composite, group],
+			
+			structure | widget | window => &[roletype],
+			
+			meter => &[range],
+			
+			searchbox => &[textbox],
+			
+			switch => &[checkbox],
+		}
+	}
+	
+	/// Is this role `role` or a subclass of it, eg `switch` is a `checkbox`, an `input` and a `widget`?
+	pub fn is_a(&self, role: AriaRole) -> bool
+	{
+		*self == role || self.superclasses().iter().any(|superclass| superclass.is_a(role))
+	}
+	
+	/// Is the accessible name of an element with this role computed from its content when it has no `aria-label` or other label, eg `button` and `link`?
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	pub fn is_named_from_content(&self) -> bool
+	{
+		use self::AriaRole::*;
+		
+		match *self
+		{
+			button | cell | checkbox | columnheader | gridcell | heading | link | menuitem | menuitemcheckbox | menuitemradio | option | radio | row | rowheader | switch | tab | tooltip | treeitem => true,
+			
+			_ => false,
+		}
+	}
+	
+	/// The states and properties that must be present on an element with this role, eg `aria-checked` for `checkbox`.
+	/// Host language semantics may provide them, eg the `checked` attribute of `<input type="checkbox">`.
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	pub fn required_states_and_properties(&self) -> &'static [&'static str]
+	{
+		use self::AriaRole::*;
+		
+		match *self
+		{
+			alert | alertdialog | button | dialog | gridcell | link | log | marquee | menuitem | progressbar | spinbutton | status | tab | tabpanel | textbox | timer | tooltip | treeitem | grid | listbox | menu | menubar | radiogroup | tablist | tree | treegrid | article | columnheader | definition | directory | document | group | img | list | listitem | math | note | presentation | region | row | rowheader | separator | toolbar | application | banner | complementary | contentinfo | form | main | navigation | search | command | composite | input | landmark | range | roletype | section | sectionhead | select | structure | widget | window | blockquote | caption | cell | code | deletion | emphasis | feed | figure | generic | insertion | none | paragraph | rowgroup | searchbox | strong | subscript | superscript | table | term | time => &[],
+			
+			checkbox | menuitemcheckbox | menuitemradio | radio | switch => &["aria-checked"],
+			
+			option => &["aria-selected"],
+			
+			scrollbar => &["aria-controls", "aria-valuenow"],
+			
+			slider | meter => &["aria-valuenow"],
+			
+			combobox => &["aria-controls", "aria-expanded"],
+			
+			heading => &["aria-level"],
+		}
+	}
+	
+	/// The states and properties, other than global ones, that may be used on an element with this role, including those required and those inherited from superclasses.
+	pub fn supported_states_and_properties(&self) -> Vec<&'static str>
+	{
+		let mut supported = Vec::new();
+		self.add_supported_states_and_properties(&mut supported);
+		supported.sort();
+		supported.dedup();
+		supported
+	}
+	
+	/// The global states and properties that must not be used on an element with this role, eg `aria-label` for `generic`.
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	pub fn prohibited_states_and_properties(&self) -> &'static [&'static str]
+	{
+		use self::AriaRole::*;
+		
+		match *self
+		{
+			presentation | caption | code | deletion | emphasis | generic | insertion | none | paragraph | strong | subscript | superscript => &["aria-label", "aria-labelledby"],
+			
+			alert | alertdialog | button | checkbox | dialog | gridcell | link | log | marquee | menuitem | menuitemcheckbox | menuitemradio | option | progressbar | radio | scrollbar | slider | spinbutton | status | tab | tabpanel | textbox | timer | tooltip | treeitem | combobox | grid | listbox | menu | menubar | radiogroup | tablist | tree | treegrid | article | columnheader | definition | directory | document | group | heading | img | list | listitem | math | note | region | row | rowheader | separator | toolbar | application | banner | complementary | contentinfo | form | main | navigation | search | command | composite | input | landmark | range | roletype | section | sectionhead | select | structure | widget | window | blockquote | cell | feed | figure | meter | rowgroup | searchbox | switch | table | term | time => &[],
+		}
+	}
+	
+	/// May `name` (eg `aria-checked`) be used on an element with this role?
+	#[inline(always)]
+	pub fn supports_state_or_property(&self, name: &str) -> bool
+	{
+		if self.prohibited_states_and_properties().contains(&name)
+		{
+			false
+		}
+		else if Self::GLOBAL_STATES_AND_PROPERTIES.contains(&name)
+		{
+			true
+		}
+		else
+		{
+			self.supported_states_and_properties().contains(&name)
+		}
+	}
+	
+	fn add_supported_states_and_properties(&self, supported: &mut Vec<&'static str>)
+	{
+		supported.extend_from_slice(self.own_supported_states_and_properties());
+		supported.extend_from_slice(self.required_states_and_properties());
+		for superclass in self.superclasses()
+		{
+			superclass.add_supported_states_and_properties(supported);
+		}
+	}
+	
+	// The states and properties, other than global and required ones, that this role supports excluding those inherited from superclasses.
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	fn own_supported_states_and_properties(&self) -> &'static [&'static str]
+	{
+		use self::AriaRole::*;
+		
+		match *self
+		{
+			alert | alertdialog | dialog | log | marquee | menuitemcheckbox | menuitemradio | progressbar | status | tabpanel | timer | tooltip | menu | menubar | treegrid | definition | directory | heading | img | list | math | note | presentation | region | banner | complementary | contentinfo | form | main | navigation | search | command | input | landmark | roletype | section | sectionhead | structure | widget | blockquote | caption | code | deletion | emphasis | feed | figure | generic | insertion | meter | none | paragraph | rowgroup | searchbox | strong | subscript | superscript | switch | term | time => &[],
+			
+			button => &["aria-expanded", "aria-pressed"],
+			
+			checkbox => &["aria-expanded", "aria-readonly", "aria-required"],
+			
+			gridcell => &["aria-expanded", "aria-readonly", "aria-required", "aria-selected"],
+			
+			link | treeitem | document => &["aria-expanded"],
+			
+			menuitem => &["aria-expanded", "aria-posinset", "aria-setsize"],
+			
+			option => &["aria-checked", "aria-posinset", "aria-setsize"],
+			
+			radio | article => &["aria-posinset", "aria-setsize"],
+			
+			scrollbar | separator | toolbar | select => &["aria-orientation"],
+			
+			slider => &["aria-orientation", "aria-readonly"],
+			
+			spinbutton | radiogroup => &["aria-readonly", "aria-required"],
+			
+			tab => &["aria-expanded", "aria-posinset", "aria-selected", "aria-setsize"],
+			
+			textbox => &["aria-activedescendant", "aria-autocomplete", "aria-multiline", "aria-placeholder", "aria-readonly", "aria-required"],
+			
+			combobox => &["aria-activedescendant", "aria-autocomplete", "aria-readonly", "aria-required"],
+			
+			grid => &["aria-multiselectable", "aria-readonly"],
+			
+			listbox => &["aria-expanded", "aria-multiselectable", "aria-readonly", "aria-required"],
+			
+			tablist => &["aria-multiselectable", "aria-orientation"],
+			
+			tree => &["aria-multiselectable", "aria-required"],
+			
+			columnheader => &["aria-sort"],
+			
+			group | composite => &["aria-activedescendant"],
+			
+			listitem => &["aria-level", "aria-posinset", "aria-setsize"],
+			
+			row => &["aria-colindex", "aria-expanded", "aria-level", "aria-posinset", "aria-rowindex", "aria-selected", "aria-setsize"],
+			
+			rowheader => &["aria-expanded", "aria-sort"],
+			
+			application => &["aria-activedescendant", "aria-expanded"],
+			
+			range => &["aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"],
+			
+			window => &["aria-modal"],
+			
+			cell => &["aria-colindex", "aria-colspan", "aria-rowindex", "aria-rowspan"],
+			
+			table => &["aria-colcount", "aria-rowcount"],
+		}
+	}
+	
 	//noinspection SpellCheckingInspection
 	#[inline(always)]
 	fn to_str(&self) -> &'static str
@@ -328,6 +957,74 @@ impl AriaRole
 			navigation => "navigation",
 			
 			search => "search",
+			
+			command => "command",
+			
+			composite => "composite",
+			
+			input => "input",
+			
+			landmark => "landmark",
+			
+			range => "range",
+			
+			roletype => "roletype",
+			
+			section => "section",
+			
+			sectionhead => "sectionhead",
+			
+			select => "select",
+			
+			structure => "structure",
+			
+			widget => "widget",
+			
+			window => "window",
+			
+			blockquote => "blockquote",
+			
+			caption => "caption",
+			
+			cell => "cell",
+			
+			code => "code",
+			
+			deletion => "deletion",
+			
+			emphasis => "emphasis",
+			
+			feed => "feed",
+			
+			figure => "figure",
+			
+			generic => "generic",
+			
+			insertion => "insertion",
+			
+			meter => "meter",
+			
+			none => "none",
+			
+			paragraph => "paragraph",
+			
+			rowgroup => "rowgroup",
+			
+			searchbox => "searchbox",
+			
+			strong => "strong",
+			
+			subscript => "subscript",
+			
+			superscript => "superscript",
+			
+			switch => "switch",
+			
+			table => "table",
+			
+			term => "term",
+			
+			time => "time",
 		}
 	}
 }
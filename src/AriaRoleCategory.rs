@@ -0,0 +1,29 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// The categories of the WAI-ARIA 1.2 [role taxonomy](https://www.w3.org/TR/wai-aria-1.2/#role_categories).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AriaRoleCategory
+{
+	/// Roles used to build the taxonomy, eg `widget`; they must not be used in content.
+	Abstract,
+	
+	/// Standalone user interface widgets, eg `button`.
+	Widget,
+	
+	/// Widgets that contain and manage other widgets, eg `listbox`.
+	Composite,
+	
+	/// Structures that organize content, eg `heading` and `table`.
+	DocumentStructure,
+	
+	/// Regions of a page that a user might want to navigate to, eg `main`.
+	Landmark,
+	
+	/// Regions whose content changes, eg `alert`.
+	LiveRegion,
+	
+	/// Browser or application windows, eg `dialog`.
+	Window,
+}
@@ -15,6 +15,10 @@ pub trait AttributeExt: Sized
 	{
 		Self::local(local_name, "")
 	}
+	
+	/// Does this attribute have the qualified name `qualified_name`, ie its local name preceded by its prefix and a colon, if it has a prefix, eg `xlink:href`?
+	#[inline(always)]
+	fn has_qualified_name(&self, qualified_name: &str) -> bool;
 }
 
 impl AttributeExt for Attribute
@@ -28,4 +32,20 @@ impl AttributeExt for Attribute
 			value: StrTendril::from_slice(value),
 		}
 	}
+	
+	#[inline(always)]
+	fn has_qualified_name(&self, qualified_name: &str) -> bool
+	{
+		let local_name = self.name.local.deref();
+		match self.name.prefix
+		{
+			None => local_name == qualified_name,
+			
+			Some(ref prefix) =>
+			{
+				let prefix = prefix.deref();
+				qualified_name.len() == prefix.len() + 1 + local_name.len() && qualified_name.starts_with(prefix) && qualified_name[prefix.len() .. ].starts_with(':') && qualified_name.ends_with(local_name)
+			}
+		}
+	}
 }
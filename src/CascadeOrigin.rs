@@ -0,0 +1,17 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Where a rule matched by `CascadeResolver` came from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CascadeOrigin
+{
+	/// A stylesheet passed to `CascadeResolver::new()`, by its index.
+	ExternalStylesheet(usize),
+	
+	/// A `<style>` element, by its index in document order.
+	StyleElement(usize),
+	
+	/// The `style` attribute of the element itself.
+	StyleAttribute,
+}
@@ -0,0 +1,305 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Finds the CSS that applies to the elements of a HTML DOM, and from where it comes, eg to debug templates.
+///
+/// Rules come from caller-supplied external stylesheets, which are treated as if linked before any `<style>` element, then from `<style>` elements in document order, then from `style` attributes.
+/// Rules inside at-rules, such as `@media`, and `<style>` elements with a `media` attribute are ignored, as whether they apply depends on the device.
+/// Dynamic pseudo-classes, such as `:hover`, do not match and rules for pseudo-elements, such as `::before`, are ignored.
+/// Shorthand properties, such as `margin`, are not expanded into their longhands, such as `margin-top`, but a shorthand that wins the cascade over one of its longhands overrides it.
+#[derive(Debug, Clone)]
+pub struct CascadeResolver
+{
+	style_rules: Vec<(CascadeOrigin, Vec<OurSelector>, PropertyDeclarations<Importance>)>,
+}
+
+impl CascadeResolver
+{
+	/// The specificity of a `style` attribute; greater than that of any selector.
+	pub const STYLE_ATTRIBUTE_SPECIFICITY: u32 = ::std::u32::MAX;
+	
+	//noinspection SpellCheckingInspection
+	const INHERITED_PROPERTIES: [&'static str; 41] =
+	[
+		"border-collapse",
+		"border-spacing",
+		"caption-side",
+		"color",
+		"cursor",
+		"direction",
+		"empty-cells",
+		"font",
+		"font-family",
+		"font-feature-settings",
+		"font-kerning",
+		"font-size",
+		"font-size-adjust",
+		"font-stretch",
+		"font-style",
+		"font-variant",
+		"font-weight",
+		"hyphens",
+		"letter-spacing",
+		"line-height",
+		"list-style",
+		"list-style-image",
+		"list-style-position",
+		"list-style-type",
+		"orphans",
+		"overflow-wrap",
+		"quotes",
+		"tab-size",
+		"text-align",
+		"text-align-last",
+		"text-indent",
+		"text-justify",
+		"text-shadow",
+		"text-transform",
+		"visibility",
+		"white-space",
+		"widows",
+		"word-break",
+		"word-spacing",
+		"word-wrap",
+		"writing-mode",
+	];
+	
+	// Each shorthand with every longhand (and shorthand) it sets.
+	const SHORTHANDS: [(&'static str, &'static [&'static str]); 13] =
+	[
+		("background", &["background-attachment", "background-clip", "background-color", "background-image", "background-origin", "background-position", "background-repeat", "background-size"]),
+		("border", &["border-bottom", "border-bottom-color", "border-bottom-style", "border-bottom-width", "border-color", "border-left", "border-left-color", "border-left-style", "border-left-width", "border-right", "border-right-color", "border-right-style", "border-right-width", "border-style", "border-top", "border-top-color", "border-top-style", "border-top-width", "border-width"]),
+		("border-bottom", &["border-bottom-color", "border-bottom-style", "border-bottom-width"]),
+		("border-color", &["border-bottom-color", "border-left-color", "border-right-color", "border-top-color"]),
+		("border-left", &["border-left-color", "border-left-style", "border-left-width"]),
+		("border-right", &["border-right-color", "border-right-style", "border-right-width"]),
+		("border-style", &["border-bottom-style", "border-left-style", "border-right-style", "border-top-style"]),
+		("border-top", &["border-top-color", "border-top-style", "border-top-width"]),
+		("border-width", &["border-bottom-width", "border-left-width", "border-right-width", "border-top-width"]),
+		("font", &["font-family", "font-size", "font-stretch", "font-style", "font-variant", "font-weight", "line-height"]),
+		("list-style", &["list-style-image", "list-style-position", "list-style-type"]),
+		("margin", &["margin-bottom", "margin-left", "margin-right", "margin-top"]),
+		("padding", &["padding-bottom", "padding-left", "padding-right", "padding-top"]),
+	];
+	
+	/// Creates a new instance for `rc_dom`, parsing its `<style>` elements.
+	/// `context` is used to report `<style>` elements whose CSS does not parse.
+	pub fn new(rc_dom: &RcDom, external_stylesheets: Vec<Stylesheet>, context: &Path) -> Result<Self, HtmlError>
+	{
+		let mut style_rules = Vec::new();
+		
+		for (index, external_stylesheet) in external_stylesheets.into_iter().enumerate()
+		{
+			Self::push_style_rules(&mut style_rules, CascadeOrigin::ExternalStylesheet(index), external_stylesheet);
+		}
+		
+		for (index, style_element) in rc_dom.select_all(&parse_css_selector("style:not([media])").unwrap()).enumerate()
+		{
+			let stylesheet = Self::parse_style_element(&style_element, context)?;
+			Self::push_style_rules(&mut style_rules, CascadeOrigin::StyleElement(index), stylesheet);
+		}
+		
+		Ok
+		(
+			Self
+			{
+				style_rules,
+			}
+		)
+	}
+	
+	/// The rules that apply to `node`, in cascade order, ie by specificity and then by source order, with the `style` attribute, if any, last.
+	/// Declarations that do not win the cascade, taking `!important` into account, are marked as overridden.
+	/// Shorthands are not expanded, but a declaration of a longhand, eg `margin-top`, is also overridden by a winning declaration of one of its shorthands, eg `margin`, that comes later or is `!important` (see `MatchedDeclaration.overridden`).
+	/// A `style` attribute that does not parse is ignored.
+	pub fn matched_rules(&self, node: &Rc<Node>) -> Vec<MatchedRule>
+	{
+		let mut matched_rules = Vec::new();
+		
+		for &(origin, ref selectors, ref property_declarations) in self.style_rules.iter()
+		{
+			let most_specific_matching_selector = selectors.iter().filter(|selector| node.matches_with_dynamic_pseudo_class_policy(selector, &DynamicPseudoClassPolicy::NeverMatch)).max_by_key(|selector| selector.specificity());
+			if let Some(selector) = most_specific_matching_selector
+			{
+				matched_rules.push(Self::matched_rule(origin, Some(selector.clone()), selector.specificity(), property_declarations));
+			}
+		}
+		
+		if let Some(property_declarations) = node.get_attribute("style").and_then(|style_attribute| Self::parse_style_attribute(&style_attribute))
+		{
+			matched_rules.push(Self::matched_rule(CascadeOrigin::StyleAttribute, None, Self::STYLE_ATTRIBUTE_SPECIFICITY, &property_declarations));
+		}
+		
+		// A stable sort, so rules of equal specificity remain in source order.
+		matched_rules.sort_by_key(|matched_rule| matched_rule.specificity);
+		
+		let mut winners: HashMap<String, (Importance, usize, usize)> = HashMap::new();
+		for (rule_index, matched_rule) in matched_rules.iter().enumerate()
+		{
+			for (declaration_index, matched_declaration) in matched_rule.declarations.iter().enumerate()
+			{
+				let importance = matched_declaration.declaration.importance;
+				let winner = winners.entry(Self::property_name(&matched_declaration.declaration)).or_insert((importance, rule_index, declaration_index));
+				if importance >= winner.0
+				{
+					*winner = (importance, rule_index, declaration_index);
+				}
+			}
+		}
+		for (name, winner) in winners.iter()
+		{
+			if !Self::is_overridden_by_shorthand(name, winner, &winners)
+			{
+				let &(_, rule_index, declaration_index) = winner;
+				matched_rules[rule_index].declarations[declaration_index].overridden = false;
+			}
+		}
+		
+		matched_rules
+	}
+	
+	/// The value of every property that applies to `node`, by property name (including any vendor prefix, eg `-webkit-appearance`), after the cascade and inheritance.
+	/// Only properties given a value by CSS are present, ie initial values are not.
+	/// Shorthands are not expanded, so both a shorthand and its longhands may be present, eg `margin: 0` alongside `margin-top: 5px` if `margin-top` came later in the cascade; a longhand is absent if its shorthand came later, and an inherited longhand is absent if its shorthand is specified.
+	pub fn computed_values(&self, node: &Rc<Node>) -> BTreeMap<String, ComputedValue>
+	{
+		let mut elements: Vec<Rc<Node>> = node.ancestors().filter(|ancestor| match ancestor.data { NodeData::Element { .. } => true, _ => false }).collect();
+		elements.reverse();
+		elements.push(node.clone());
+		
+		let mut computed_values: BTreeMap<String, ComputedValue> = BTreeMap::new();
+		for element in elements.iter()
+		{
+			let parent_computed_values = replace(&mut computed_values, BTreeMap::new());
+			
+			for (name, parent_computed_value) in parent_computed_values.iter()
+			{
+				if Self::is_inherited(name)
+				{
+					computed_values.insert(name.clone(), ComputedValue { value: parent_computed_value.value.clone(), inherited: true });
+				}
+			}
+			
+			let matched_declarations: Vec<MatchedDeclaration> = self.matched_rules(element).into_iter().flat_map(|matched_rule| matched_rule.declarations.into_iter()).filter(|matched_declaration| !matched_declaration.overridden).collect();
+			
+			// A shorthand specified for this element resets any longhands inherited from its parent.
+			for matched_declaration in matched_declarations.iter()
+			{
+				let name = Self::property_name(&matched_declaration.declaration);
+				if let Some(&(_, longhands)) = Self::SHORTHANDS.iter().find(|&&(shorthand, _)| shorthand == name)
+				{
+					for longhand in longhands.iter()
+					{
+						computed_values.remove(*longhand);
+					}
+				}
+			}
+			
+			for matched_declaration in matched_declarations.into_iter()
+			{
+				let name = Self::property_name(&matched_declaration.declaration);
+				match matched_declaration.declaration.value
+				{
+					UnparsedPropertyValue::SpecifiedValue(ref specified_value) =>
+					{
+						computed_values.insert(name, ComputedValue { value: specified_value.originalCss.trim().to_owned(), inherited: false });
+					}
+					
+					UnparsedPropertyValue::CssWideKeyword(css_wide_keyword) =>
+					{
+						let inherits = match css_wide_keyword
+						{
+							CssWideKeyword::inherit => true,
+							CssWideKeyword::initial => false,
+							CssWideKeyword::unset => Self::is_inherited(&name),
+						};
+						
+						match parent_computed_values.get(&name)
+						{
+							Some(parent_computed_value) if inherits => computed_values.insert(name, ComputedValue { value: parent_computed_value.value.clone(), inherited: true }),
+							_ => computed_values.remove(&name),
+						};
+					}
+				}
+			}
+		}
+		computed_values
+	}
+	
+	#[inline(always)]
+	fn push_style_rules(style_rules: &mut Vec<(CascadeOrigin, Vec<OurSelector>, PropertyDeclarations<Importance>)>, origin: CascadeOrigin, stylesheet: Stylesheet)
+	{
+		for css_rule in stylesheet.rules.0
+		{
+			if let CssRule::Style(style_rule) = css_rule
+			{
+				let selectors: Vec<OurSelector> = style_rule.selectors.0.into_iter().filter(|selector| !selector.has_pseudo_element()).collect();
+				if !selectors.is_empty()
+				{
+					style_rules.push((origin, selectors, style_rule.property_declarations));
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn matched_rule(origin: CascadeOrigin, selector: Option<OurSelector>, specificity: u32, property_declarations: &PropertyDeclarations<Importance>) -> MatchedRule
+	{
+		MatchedRule
+		{
+			origin,
+			selector,
+			specificity,
+			declarations: property_declarations.0.iter().map(|declaration| MatchedDeclaration { declaration: declaration.clone(), overridden: true }).collect(),
+		}
+	}
+	
+	#[inline(always)]
+	fn property_name(property_declaration: &PropertyDeclaration<Importance>) -> String
+	{
+		let mut name = String::new();
+		if let Some(ref vendor_prefix) = property_declaration.vendor_prefix
+		{
+			vendor_prefix.to_css(&mut name).unwrap();
+		}
+		name.push_str(&property_declaration.name.to_ascii_lowercase());
+		name
+	}
+	
+	#[inline(always)]
+	fn is_overridden_by_shorthand(name: &str, winner: &(Importance, usize, usize), winners: &HashMap<String, (Importance, usize, usize)>) -> bool
+	{
+		Self::SHORTHANDS.iter().filter(|&&(_, longhands)| longhands.contains(&name)).any(|&(shorthand, _)| match winners.get(shorthand)
+		{
+			Some(shorthand_winner) => shorthand_winner > winner,
+			None => false,
+		})
+	}
+	
+	#[inline(always)]
+	fn is_inherited(name: &str) -> bool
+	{
+		name.starts_with("--") || Self::INHERITED_PROPERTIES.binary_search(&name).is_ok()
+	}
+	
+	// Parses the CSS of a `<style>` element; also used by `CssInliner` and `RcDomExt.purify_style_elements()`.
+	#[inline(always)]
+	fn parse_style_element(style_element: &Rc<Node>, context: &Path) -> Result<Stylesheet, HtmlError>
+	{
+		let css = style_element.text_content();
+		Stylesheet::parse(&css).map_err(|cause| HtmlError::InvalidFile(context.to_path_buf(), format!("The CSS of a <style> element could not be parsed because of {:?} at {:?}", cause.error, cause.location)))
+	}
+	
+	// Parses the declarations of a `style` attribute by wrapping them in a rule; also used by `CssInliner`.
+	#[inline(always)]
+	fn parse_style_attribute(style_attribute: &str) -> Option<PropertyDeclarations<Importance>>
+	{
+		let stylesheet = Stylesheet::parse(&format!("*{{{}}}", style_attribute)).ok()?;
+		match stylesheet.rules.0.into_iter().next()
+		{
+			Some(CssRule::Style(style_rule)) => Some(style_rule.property_declarations),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,14 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// How confident the HTML 5 encoding sniffing algorithm is of the character encoding it chose.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum CharacterEncodingConfidence
+{
+	/// The character encoding came from a byte order mark or the transport layer (eg a HTTP `Content-Type` header's `charset`).
+	Certain,
+	
+	/// The character encoding came from a `<meta charset>` or `<meta http-equiv="Content-Type">` element, or is the default of `windows-1252`.
+	Tentative,
+}
@@ -0,0 +1,52 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An iterator over the children of a node that are elements, skipping text, comments and processing instructions; returned by `NodeExt::children_elements()`.
+#[derive(Clone)]
+pub struct ChildrenElements
+{
+	parent: Rc<Node>,
+	next_index: usize,
+}
+
+impl Debug for ChildrenElements
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "ChildrenElements {{ next_index: {} }}", self.next_index)
+	}
+}
+
+impl Iterator for ChildrenElements
+{
+	type Item = Rc<Node>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop
+		{
+			let child = self.parent.children.borrow().get(self.next_index).cloned()?;
+			self.next_index += 1;
+			if let NodeData::Element { .. } = child.data
+			{
+				return Some(child)
+			}
+		}
+	}
+}
+
+impl ChildrenElements
+{
+	#[inline(always)]
+	fn new(parent: &Rc<Node>) -> Self
+	{
+		Self
+		{
+			parent: parent.clone(),
+			next_index: 0,
+		}
+	}
+}
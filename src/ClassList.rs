@@ -0,0 +1,168 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A view of the classes of an element, like `Element.classList`; returned by `NodeExt::class_list()`.
+/// The `class` attribute is tokenised on `SELECTOR_WHITESPACE` as for matching `.class` selectors.
+/// Changes write back a normalised `class` attribute, ie without duplicates and with classes separated by a single space.
+/// Classes passed to the methods that change classes must not be empty or contain whitespace; if one is, they fail with `InvalidClass` and change nothing.
+#[derive(Clone)]
+pub struct ClassList
+{
+	node: Rc<Node>,
+}
+
+impl Debug for ClassList
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "ClassList {{ classes: {:?} }}", self.classes())
+	}
+}
+
+impl IntoIterator for ClassList
+{
+	type Item = String;
+	
+	type IntoIter = ::std::vec::IntoIter<String>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl ClassList
+{
+	/// Iterates over the classes, in order and without duplicates.
+	#[inline(always)]
+	pub fn iter(&self) -> ::std::vec::IntoIter<String>
+	{
+		self.classes().into_iter()
+	}
+	
+	/// The number of classes, not counting duplicates.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.classes().len()
+	}
+	
+	/// Are there no classes?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.classes().is_empty()
+	}
+	
+	/// Is `class` one of the classes?
+	/// Always false if `class` is empty or contains whitespace.
+	#[inline(always)]
+	pub fn contains(&self, class: &str) -> bool
+	{
+		InvalidClass::is_valid(class) && self.classes().iter().any(|existing| existing == class)
+	}
+	
+	/// Adds `class`, if it is not already present.
+	#[inline(always)]
+	pub fn add(&self, class: &str) -> Result<(), InvalidClass>
+	{
+		InvalidClass::validate(class)?;
+		let mut classes = self.classes();
+		if !classes.iter().any(|existing| existing == class)
+		{
+			classes.push(class.to_owned());
+		}
+		self.write(classes);
+		Ok(())
+	}
+	
+	/// Removes `class`, if it is present.
+	#[inline(always)]
+	pub fn remove(&self, class: &str) -> Result<(), InvalidClass>
+	{
+		InvalidClass::validate(class)?;
+		let mut classes = self.classes();
+		classes.retain(|existing| existing != class);
+		self.write(classes);
+		Ok(())
+	}
+	
+	/// Removes `class` if it is present, or otherwise adds it.
+	/// Returns whether `class` is now present.
+	#[inline(always)]
+	pub fn toggle(&self, class: &str) -> Result<bool, InvalidClass>
+	{
+		if self.contains(class)
+		{
+			self.remove(class)?;
+			Ok(false)
+		}
+		else
+		{
+			self.add(class)?;
+			Ok(true)
+		}
+	}
+	
+	/// Replaces `class` with `new_class`, in the same position, if `class` is present.
+	/// Returns whether `class` was present.
+	#[inline(always)]
+	pub fn replace(&self, class: &str, new_class: &str) -> Result<bool, InvalidClass>
+	{
+		InvalidClass::validate(class)?;
+		InvalidClass::validate(new_class)?;
+		
+		let mut classes = self.classes();
+		match classes.iter().position(|existing| existing == class)
+		{
+			None => Ok(false),
+			Some(index) =>
+			{
+				classes[index] = new_class.to_owned();
+				let mut seen = HashSet::with_capacity(classes.len());
+				classes.retain(|class| seen.insert(class.clone()));
+				self.write(classes);
+				Ok(true)
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn new(node: &Rc<Node>) -> Self
+	{
+		Self
+		{
+			node: node.clone(),
+		}
+	}
+	
+	#[inline(always)]
+	fn classes(&self) -> Vec<String>
+	{
+		let mut classes: Vec<String> = Vec::new();
+		if let Some(class_attribute) = self.node.get_attribute("class")
+		{
+			for class in class_attribute.split(SELECTOR_WHITESPACE).filter(|class| !class.is_empty())
+			{
+				if !classes.iter().any(|existing| existing == class)
+				{
+					classes.push(class.to_owned());
+				}
+			}
+		}
+		classes
+	}
+	
+	// Does not add an empty `class` attribute to an element which does not have one.
+	#[inline(always)]
+	fn write(&self, classes: Vec<String>)
+	{
+		if !classes.is_empty() || self.node.has_attribute("class")
+		{
+			self.node.set_attribute("class", &classes.join(" "));
+		}
+	}
+}
@@ -0,0 +1,14 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// The value of a property of an element; returned by `CascadeResolver::computed_values()`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ComputedValue
+{
+	/// The value as specified in CSS, eg `1.5em` or `var(--main-color)`; units and variables are not resolved.
+	pub value: String,
+	
+	/// Was this value inherited from an ancestor, either because the property is inherited or because it was specified as `inherit`?
+	pub inherited: bool,
+}
@@ -0,0 +1,123 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A category of content from the HTML 5 specification's [content models](https://html.spec.whatwg.org/multipage/dom.html#kinds-of-content).
+/// Elements can be in several categories, eg `<a>` is flow, phrasing and interactive content.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ContentCategory
+{
+	/// Content that sets up the presentation or behaviour of the rest of the content, eg `<link>` and `<meta>`.
+	Metadata,
+	
+	/// Most elements that are used in the body of documents.
+	Flow,
+	
+	/// Content that defines the scope of headings and footers, eg `<article>` and `<section>`.
+	Sectioning,
+	
+	/// Content that defines the header of a section, eg `<h1>`.
+	Heading,
+	
+	/// The text of the document and the elements that mark up that text at the intra-paragraph level, eg `<em>`.
+	Phrasing,
+	
+	/// Content that imports another resource into the document, eg `<img>`.
+	Embedded,
+	
+	/// Content that is specifically intended for user interaction, eg `<button>`.
+	Interactive,
+}
+
+impl ContentCategory
+{
+	/// Is `node` in this category?
+	/// Text nodes are flow and phrasing content; other non-element nodes and elements not in the HTML namespace (other than `<svg>` and `<math>`) are in no category.
+	/// Custom elements (those with a hyphen in their name) are treated as flow and phrasing content.
+	//noinspection SpellCheckingInspection
+	pub fn includes(self, node: &Rc<Node>) -> bool
+	{
+		use self::ContentCategory::*;
+		
+		let (name, attributes) = match node.data
+		{
+			Text { .. } => return match self
+			{
+				Flow | Phrasing => true,
+				_ => false,
+			},
+			
+			NodeData::Element { ref name, ref attrs, .. } => (name, attrs.borrow()),
+			
+			_ => return false,
+		};
+		
+		if name.ns == ns!(svg) || name.ns == ns!(mathml)
+		{
+			return match (self, &name.local)
+			{
+				(Flow, &local_name!("svg")) | (Phrasing, &local_name!("svg")) | (Embedded, &local_name!("svg")) => true,
+				(Flow, &local_name!("math")) | (Phrasing, &local_name!("math")) | (Embedded, &local_name!("math")) => true,
+				_ => false,
+			}
+		}
+		
+		if !name.is_unprefixed_and_html_namespace_or_none()
+		{
+			return false;
+		}
+		
+		let has = |attribute_name: LocalName| attributes.iter().any(|attribute| attribute.name.is_only_local(&attribute_name));
+		let local = &name.local;
+		
+		match self
+		{
+			Metadata => match *local
+			{
+				local_name!("base") | local_name!("link") | local_name!("meta") | local_name!("noscript") | local_name!("script") | local_name!("style") | local_name!("template") | local_name!("title") => true,
+				_ => false,
+			},
+			
+			Flow => match *local
+			{
+				local_name!("address") | local_name!("article") | local_name!("aside") | local_name!("blockquote") | local_name!("details") | local_name!("dialog") | local_name!("div") | local_name!("dl") | local_name!("fieldset") | local_name!("figure") | local_name!("footer") | local_name!("form") | local_name!("h1") | local_name!("h2") | local_name!("h3") | local_name!("h4") | local_name!("h5") | local_name!("h6") | local_name!("header") | local_name!("hgroup") | local_name!("hr") | local_name!("main") | local_name!("menu") | local_name!("nav") | local_name!("ol") | local_name!("p") | local_name!("pre") | local_name!("section") | local_name!("table") | local_name!("ul") => true,
+				_ => &**local == "search" || Phrasing.includes(node),
+			},
+			
+			Sectioning => match *local
+			{
+				local_name!("article") | local_name!("aside") | local_name!("nav") | local_name!("section") => true,
+				_ => false,
+			},
+			
+			Heading => match *local
+			{
+				local_name!("h1") | local_name!("h2") | local_name!("h3") | local_name!("h4") | local_name!("h5") | local_name!("h6") | local_name!("hgroup") => true,
+				_ => false,
+			},
+			
+			Phrasing => match *local
+			{
+				local_name!("a") | local_name!("abbr") | local_name!("area") | local_name!("audio") | local_name!("b") | local_name!("bdi") | local_name!("bdo") | local_name!("br") | local_name!("button") | local_name!("canvas") | local_name!("cite") | local_name!("code") | local_name!("data") | local_name!("datalist") | local_name!("del") | local_name!("dfn") | local_name!("em") | local_name!("embed") | local_name!("i") | local_name!("iframe") | local_name!("img") | local_name!("input") | local_name!("ins") | local_name!("kbd") | local_name!("label") | local_name!("map") | local_name!("mark") | local_name!("meter") | local_name!("noscript") | local_name!("object") | local_name!("output") | local_name!("progress") | local_name!("q") | local_name!("ruby") | local_name!("s") | local_name!("samp") | local_name!("script") | local_name!("select") | local_name!("small") | local_name!("span") | local_name!("strong") | local_name!("sub") | local_name!("sup") | local_name!("template") | local_name!("textarea") | local_name!("time") | local_name!("u") | local_name!("var") | local_name!("video") | local_name!("wbr") => true,
+				local_name!("link") | local_name!("meta") => has(local_name!("itemprop")),
+				_ => &**local == "picture" || &**local == "slot" || local.contains('-'),
+			},
+			
+			Embedded => match *local
+			{
+				local_name!("audio") | local_name!("canvas") | local_name!("embed") | local_name!("iframe") | local_name!("img") | local_name!("object") | local_name!("video") => true,
+				_ => &**local == "picture",
+			},
+			
+			Interactive => match *local
+			{
+				local_name!("a") => has(local_name!("href")),
+				local_name!("audio") | local_name!("video") => has(local_name!("controls")),
+				local_name!("img") => has(local_name!("usemap")),
+				local_name!("input") => !attributes.iter().any(|attribute| attribute.name.is_only_local(&local_name!("type")) && attribute.value.eq_ignore_ascii_case("hidden")),
+				local_name!("button") | local_name!("details") | local_name!("embed") | local_name!("iframe") | local_name!("label") | local_name!("select") | local_name!("textarea") => true,
+				_ => false,
+			},
+		}
+	}
+}
@@ -0,0 +1,392 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Checks that a HTML DOM conforms to the [content models](https://html.spec.whatwg.org/multipage/dom.html#content-models) of the HTML 5 specification.
+/// This catches mistakes that `RcDomExt.verify()` can not, such as a `<div>` inside a `<p>` or a `<tr>` outside a table created using `UnattachedNode`; such DOMs do not re-parse identically once serialized.
+/// Checks are made of permitted children (using the flow, phrasing and metadata content categories and per-element rules), prohibited descendants, void elements with children, elements that should occur only once and required attributes.
+/// Elements in the SVG and MathML namespaces are not checked themselves, but any HTML elements inside them are; only HTML integration points (eg `<foreignObject>`) permit HTML children.
+/// Custom elements are transparent.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ContentModelValidator
+{
+	check_required_attributes: bool,
+}
+
+impl Default for ContentModelValidator
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			check_required_attributes: true,
+		}
+	}
+}
+
+impl ContentModelValidator
+{
+	/// Check that elements have their required attributes, eg `<img src>`?
+	/// Defaults to true.
+	#[inline(always)]
+	pub fn with_check_required_attributes(mut self, check_required_attributes: bool) -> Self
+	{
+		self.check_required_attributes = check_required_attributes;
+		self
+	}
+	
+	/// Validates a HTML DOM, returning every violation found in document order.
+	/// Violations are located by path; use `validate_with_source_locations()` to also locate them by line.
+	#[inline(always)]
+	pub fn validate(&self, rc_dom: &RcDom) -> Vec<ContentModelViolation>
+	{
+		self.validate_optionally_with_source_locations(rc_dom, None)
+	}
+	
+	/// Validates a HTML DOM parsed with one of the `RcDomExt` methods ending in `_with_source_locations`, returning every violation found in document order.
+	#[inline(always)]
+	pub fn validate_with_source_locations(&self, rc_dom: &RcDom, source_locations: &SourceLocations) -> Vec<ContentModelViolation>
+	{
+		self.validate_optionally_with_source_locations(rc_dom, Some(source_locations))
+	}
+	
+	#[inline(always)]
+	fn validate_optionally_with_source_locations(&self, rc_dom: &RcDom, source_locations: Option<&SourceLocations>) -> Vec<ContentModelViolation>
+	{
+		let mut ancestors = Vec::new();
+		let mut once_only_elements_seen = Vec::new();
+		let mut violations = Vec::new();
+		self.validate_children(&rc_dom.document, &mut ancestors, &mut once_only_elements_seen, source_locations, &mut violations);
+		violations
+	}
+	
+	fn validate_children(&self, parent: &Rc<Node>, ancestors: &mut Vec<Rc<Node>>, once_only_elements_seen: &mut Vec<LocalName>, source_locations: Option<&SourceLocations>, violations: &mut Vec<ContentModelViolation>)
+	{
+		for child in parent.children.borrow().iter()
+		{
+			self.validate_node(child, ancestors, once_only_elements_seen, source_locations, violations);
+		}
+	}
+	
+	fn validate_node(&self, node: &Rc<Node>, ancestors: &mut Vec<Rc<Node>>, once_only_elements_seen: &mut Vec<LocalName>, source_locations: Option<&SourceLocations>, violations: &mut Vec<ContentModelViolation>)
+	{
+		use self::ContentModelViolationKind::*;
+		
+		let mut violation = |kind: ContentModelViolationKind|
+		{
+			violations.push
+			(
+				ContentModelViolation
+				{
+					node: node.clone(),
+					path: local_names_path(node, ancestors),
+					source_location: source_locations.and_then(|source_locations| source_locations.get(node)),
+					kind,
+				}
+			)
+		};
+		
+		match node.data
+		{
+			Text { ref contents } =>
+			{
+				if !is_inter_element_whitespace(contents.borrow().deref())
+				{
+					if let Some(parent) = Self::not_permitted_by(node, ancestors)
+					{
+						violation(TextNotPermittedInParent { parent });
+					}
+				}
+			}
+			
+			NodeData::Element { ref name, ref template_contents, .. } =>
+			{
+				if let Some(parent) = Self::not_permitted_by(node, ancestors)
+				{
+					violation(ElementNotPermittedInParent { parent });
+				}
+				
+				if name.is_unprefixed_and_html_namespace_or_none()
+				{
+					if !name.can_have_children() && !node.children.borrow().is_empty()
+					{
+						violation(VoidElementHasChildren);
+					}
+					
+					if let Some(ancestor) = Self::prohibited_by(node, ancestors)
+					{
+						violation(ElementNotPermittedInAncestor { ancestor });
+					}
+					
+					if Self::is_once_only(node, &name.local)
+					{
+						if once_only_elements_seen.contains(&name.local)
+						{
+							violation(Duplicate);
+						}
+						else
+						{
+							once_only_elements_seen.push(name.local.clone());
+						}
+					}
+					
+					if self.check_required_attributes
+					{
+						if let Some(one_of) = Self::missing_required_attribute(node, &name.local, ancestors.last())
+						{
+							violation(MissingRequiredAttribute { one_of });
+						}
+					}
+				}
+				
+				ancestors.push(node.clone());
+				match *template_contents
+				{
+					None => self.validate_children(node, ancestors, once_only_elements_seen, source_locations, violations),
+					Some(ref template_contents) => self.validate_children(template_contents, ancestors, once_only_elements_seen, source_locations, violations),
+				}
+				ancestors.pop();
+			}
+			
+			_ => (),
+		}
+	}
+	
+	// Returns the local name of the parent (or nearest non-transparent ancestor) whose content model does not permit `child`.
+	#[inline(always)]
+	fn not_permitted_by(child: &Rc<Node>, ancestors: &[Rc<Node>]) -> Option<LocalName>
+	{
+		for (index, ancestor) in ancestors.iter().enumerate().rev()
+		{
+			let name = match ancestor.data
+			{
+				NodeData::Element { ref name, .. } => name,
+				_ => return None,
+			};
+			
+			if !name.is_unprefixed_and_html_namespace_or_none()
+			{
+				return if Self::foreign_content_model_permits(name, child)
+				{
+					None
+				}
+				else
+				{
+					Some(name.local.clone())
+				}
+			}
+			
+			match Self::content_model_permits(&name.local, &ancestors[.. index], child)
+			{
+				Some(true) => return None,
+				Some(false) => return Some(name.local.clone()),
+				None => continue,
+			}
+		}
+		None
+	}
+	
+	// Foreign (SVG and MathML) elements permit any foreign element or text; HTML elements are only parsed as children of HTML integration points.
+	#[inline(always)]
+	fn foreign_content_model_permits(parent: &QualName, child: &Rc<Node>) -> bool
+	{
+		use self::ContentCategory::*;
+		
+		match child.data
+		{
+			NodeData::Element { ref name, .. } if name.is_unprefixed_and_html_namespace_or_none() => match (&parent.ns, &*parent.local)
+			{
+				(&ns!(svg), "foreignObject") | (&ns!(svg), "desc") | (&ns!(svg), "title") | (&ns!(mathml), "annotation-xml") => Flow.includes(child),
+				(&ns!(mathml), "mi") | (&ns!(mathml), "mo") | (&ns!(mathml), "mn") | (&ns!(mathml), "ms") | (&ns!(mathml), "mtext") => Phrasing.includes(child),
+				_ => false,
+			},
+			
+			_ => true,
+		}
+	}
+	
+	// Returns None if the parent is transparent, ie its content model is that of its parent.
+	// `ancestors_of_parent` excludes the parent itself.
+	//noinspection SpellCheckingInspection
+	fn content_model_permits(parent: &LocalName, ancestors_of_parent: &[Rc<Node>], child: &Rc<Node>) -> Option<bool>
+	{
+		use self::ContentCategory::*;
+		
+		let is_text = match child.data
+		{
+			Text { .. } => true,
+			_ => false,
+		};
+		let is_one_of = |local_names: &[LocalName]| child.is_only_local_of(local_names);
+		let is_script_supporting = is_one_of(&[local_name!("script"), local_name!("template")]);
+		
+		let permitted = match *parent
+		{
+			local_name!("html") => is_one_of(&[local_name!("head"), local_name!("body")]),
+			
+			local_name!("head") => Metadata.includes(child),
+			
+			local_name!("ul") | local_name!("ol") | local_name!("menu") => is_one_of(&[local_name!("li")]) || is_script_supporting,
+			
+			local_name!("dl") => is_one_of(&[local_name!("dt"), local_name!("dd"), local_name!("div")]) || is_script_supporting,
+			
+			local_name!("div") =>
+			{
+				let is_in_dl = ancestors_of_parent.last().map(|grandparent| grandparent.is_only_local(&local_name!("dl"))).unwrap_or(false);
+				Flow.includes(child) || (is_in_dl && is_one_of(&[local_name!("dt"), local_name!("dd")]))
+			}
+			
+			local_name!("table") => is_one_of(&[local_name!("caption"), local_name!("colgroup"), local_name!("thead"), local_name!("tbody"), local_name!("tfoot"), local_name!("tr")]) || is_script_supporting,
+			
+			local_name!("thead") | local_name!("tbody") | local_name!("tfoot") => is_one_of(&[local_name!("tr")]) || is_script_supporting,
+			
+			local_name!("tr") => is_one_of(&[local_name!("td"), local_name!("th")]) || is_script_supporting,
+			
+			local_name!("colgroup") => is_one_of(&[local_name!("col"), local_name!("template")]),
+			
+			local_name!("select") => is_one_of(&[local_name!("option"), local_name!("optgroup"), local_name!("hr")]) || is_script_supporting,
+			
+			local_name!("optgroup") => is_one_of(&[local_name!("option")]) || is_script_supporting,
+			
+			local_name!("datalist") => is_one_of(&[local_name!("option")]) || Phrasing.includes(child),
+			
+			local_name!("hgroup") => is_one_of(&[local_name!("h1"), local_name!("h2"), local_name!("h3"), local_name!("h4"), local_name!("h5"), local_name!("h6"), local_name!("p")]) || is_script_supporting,
+			
+			local_name!("ruby") => is_one_of(&[local_name!("rt"), local_name!("rp")]) || Phrasing.includes(child),
+			
+			local_name!("rp") | local_name!("option") | local_name!("title") | local_name!("textarea") | local_name!("script") | local_name!("style") | local_name!("iframe") => is_text,
+			
+			local_name!("fieldset") => is_one_of(&[local_name!("legend")]) || Flow.includes(child),
+			
+			local_name!("details") => is_one_of(&[local_name!("summary")]) || Flow.includes(child),
+			
+			local_name!("figure") => is_one_of(&[local_name!("figcaption")]) || Flow.includes(child),
+			
+			local_name!("legend") | local_name!("summary") => Phrasing.includes(child) || Heading.includes(child),
+			
+			local_name!("video") | local_name!("audio") => if is_one_of(&[local_name!("source"), local_name!("track")])
+			{
+				true
+			}
+			else
+			{
+				return None
+			},
+			
+			local_name!("object") => if is_one_of(&[local_name!("param")])
+			{
+				true
+			}
+			else
+			{
+				return None
+			},
+			
+			local_name!("a") | local_name!("ins") | local_name!("del") | local_name!("map") | local_name!("canvas") | local_name!("noscript") => return None,
+			
+			local_name!("body") | local_name!("li") | local_name!("dd") | local_name!("dt") | local_name!("blockquote") | local_name!("main") | local_name!("section") | local_name!("article") | local_name!("aside") | local_name!("nav") | local_name!("header") | local_name!("footer") | local_name!("address") | local_name!("form") | local_name!("td") | local_name!("th") | local_name!("figcaption") | local_name!("dialog") | local_name!("caption") => Flow.includes(child),
+			
+			local_name!("p") | local_name!("h1") | local_name!("h2") | local_name!("h3") | local_name!("h4") | local_name!("h5") | local_name!("h6") | local_name!("pre") | local_name!("span") | local_name!("b") | local_name!("i") | local_name!("em") | local_name!("strong") | local_name!("small") | local_name!("s") | local_name!("cite") | local_name!("q") | local_name!("dfn") | local_name!("abbr") | local_name!("data") | local_name!("time") | local_name!("code") | local_name!("var") | local_name!("samp") | local_name!("kbd") | local_name!("sub") | local_name!("sup") | local_name!("u") | local_name!("mark") | local_name!("bdi") | local_name!("bdo") | local_name!("label") | local_name!("output") | local_name!("button") | local_name!("progress") | local_name!("meter") | local_name!("rt") => Phrasing.includes(child),
+			
+			_ => match &**parent
+			{
+				"picture" => is_one_of(&[local_name!("source"), local_name!("img")]) || is_script_supporting,
+				"search" => Flow.includes(child),
+				"slot" => return None,
+				_ if parent.contains('-') => return None,
+				_ => true,
+			},
+		};
+		Some(permitted)
+	}
+	
+	// Returns the local name of the nearest ancestor that prohibits `node` as a descendant.
+	fn prohibited_by(node: &Rc<Node>, ancestors: &[Rc<Node>]) -> Option<LocalName>
+	{
+		use self::ContentCategory::*;
+		
+		let is_one_of = |local_names: &[LocalName]| node.is_only_local_of(local_names);
+		
+		for ancestor in ancestors.iter().rev()
+		{
+			let local = match ancestor.data
+			{
+				NodeData::Element { ref name, .. } if name.is_unprefixed_and_html_namespace_or_none() => &name.local,
+				_ => continue,
+			};
+			
+			let prohibited = match *local
+			{
+				local_name!("a") | local_name!("button") => Interactive.includes(node) || is_one_of(&[local_name!("a")]),
+				
+				local_name!("label") | local_name!("form") | local_name!("dfn") | local_name!("progress") | local_name!("meter") => is_one_of(&[local.clone()]),
+				
+				local_name!("header") | local_name!("footer") => is_one_of(&[local_name!("header"), local_name!("footer"), local_name!("main")]),
+				
+				local_name!("address") => Heading.includes(node) || Sectioning.includes(node) || is_one_of(&[local_name!("header"), local_name!("footer"), local_name!("address")]),
+				
+				local_name!("th") | local_name!("dt") => Heading.includes(node) || Sectioning.includes(node) || is_one_of(&[local_name!("header"), local_name!("footer")]),
+				
+				local_name!("caption") => is_one_of(&[local_name!("table")]),
+				
+				local_name!("article") | local_name!("aside") | local_name!("nav") => is_one_of(&[local_name!("main")]),
+				
+				_ => false,
+			};
+			
+			if prohibited
+			{
+				return Some(local.clone());
+			}
+		}
+		None
+	}
+	
+	#[inline(always)]
+	fn is_once_only(node: &Rc<Node>, local: &LocalName) -> bool
+	{
+		match *local
+		{
+			local_name!("head") | local_name!("body") | local_name!("title") | local_name!("base") => true,
+			
+			local_name!("main") => !node.has_attribute("hidden"),
+			
+			_ => false,
+		}
+	}
+	
+	// Returns the first unmet requirement, as a list of attributes any one of which would meet it.
+	//noinspection SpellCheckingInspection
+	fn missing_required_attribute(node: &Rc<Node>, local: &LocalName, parent: Option<&Rc<Node>>) -> Option<Vec<LocalName>>
+	{
+		let requirements = match *local
+		{
+			local_name!("img") | local_name!("track") => vec![vec![local_name!("src")]],
+			
+			local_name!("link") => vec![vec![local_name!("href")], vec![local_name!("rel"), local_name!("itemprop")]],
+			
+			local_name!("meta") => vec![vec![local_name!("name"), local_name!("http-equiv"), local_name!("charset"), local_name!("itemprop")]],
+			
+			local_name!("base") => vec![vec![local_name!("href"), local_name!("target")]],
+			
+			local_name!("bdo") => vec![vec![local_name!("dir")]],
+			
+			local_name!("optgroup") => vec![vec![local_name!("label")]],
+			
+			local_name!("object") => vec![vec![local_name!("data"), local_name!("type")]],
+			
+			local_name!("source") => match parent
+			{
+				Some(parent) if parent.is_only_local(&LocalName::from("picture")) => vec![vec![local_name!("srcset")]],
+				_ => vec![vec![local_name!("src")]],
+			},
+			
+			local_name!("area") if node.has_attribute("href") => vec![vec![local_name!("alt")]],
+			
+			_ => return None,
+		};
+		
+		requirements.into_iter().find(|one_of| !one_of.iter().any(|attribute| node.has_attribute(attribute)))
+	}
+}
@@ -0,0 +1,43 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A node that does not conform to the HTML 5 content model, found by `ContentModelValidator`.
+#[derive(Clone)]
+pub struct ContentModelViolation
+{
+	/// The node that does not conform.
+	pub node: Rc<Node>,
+	
+	/// The local names of the node's ancestors and itself, eg `html > body > p > div`; text nodes are `#text`.
+	/// Useful to locate nodes in DOMs that were not parsed.
+	pub path: String,
+	
+	/// Where in the HTML source the node started, if the DOM was parsed with source locations.
+	pub source_location: Option<SourceLocation>,
+	
+	/// Why the node does not conform.
+	pub kind: ContentModelViolationKind,
+}
+
+impl Debug for ContentModelViolation
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("ContentModelViolation").field("path", &self.path).field("source_location", &self.source_location).field("kind", &self.kind).finish()
+	}
+}
+
+impl Display for ContentModelViolation
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		if let Some(source_location) = self.source_location
+		{
+			write!(f, "{}: ", source_location)?;
+		}
+		write!(f, "{}: {}", self.path, self.kind)
+	}
+}
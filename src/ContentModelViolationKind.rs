@@ -0,0 +1,75 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Why a node does not conform to the HTML 5 content model.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ContentModelViolationKind
+{
+	/// A void element, such as `<br>` or `<img>`, has children.
+	VoidElementHasChildren,
+	
+	/// An element is not permitted as a child of `parent`, eg `<div>` in `<p>`, `<li>` outside of a list or `<tr>` outside of a table.
+	/// If the element's parent is transparent, such as `<a>`, then `parent` is its nearest ancestor that is not.
+	ElementNotPermittedInParent
+	{
+		/// The parent (or nearest non-transparent ancestor).
+		parent: LocalName,
+	},
+	
+	/// Text other than inter-element whitespace is not permitted as a child of `parent`, eg in `<ul>` or `<tr>`.
+	TextNotPermittedInParent
+	{
+		/// The parent (or nearest non-transparent ancestor).
+		parent: LocalName,
+	},
+	
+	/// An element is not permitted as a descendant of `ancestor`, eg `<a>` in `<a>`, `<form>` in `<form>` or `<main>` in `<article>`.
+	ElementNotPermittedInAncestor
+	{
+		/// The ancestor.
+		ancestor: LocalName,
+	},
+	
+	/// An element that should occur at most once in a document occurs again, eg a second `<title>` or a second `<main>` that is not `hidden`.
+	Duplicate,
+	
+	/// An element lacks a required attribute; at least one of `one_of` is needed.
+	MissingRequiredAttribute
+	{
+		/// The attributes any one of which would be sufficient.
+		one_of: Vec<LocalName>,
+	},
+}
+
+impl Display for ContentModelViolationKind
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::ContentModelViolationKind::*;
+		
+		match *self
+		{
+			VoidElementHasChildren => write!(f, "void element has children"),
+			
+			ElementNotPermittedInParent { ref parent } => write!(f, "element is not permitted as a child of <{}>", parent),
+			
+			TextNotPermittedInParent { ref parent } => write!(f, "text is not permitted as a child of <{}>", parent),
+			
+			ElementNotPermittedInAncestor { ref ancestor } => write!(f, "element is not permitted as a descendant of <{}>", ancestor),
+			
+			Duplicate => write!(f, "element occurs more than once"),
+			
+			MissingRequiredAttribute { ref one_of } =>
+			{
+				write!(f, "element is missing a required attribute (one of")?;
+				for attribute in one_of.iter()
+				{
+					write!(f, " '{}'", attribute)?;
+				}
+				write!(f, ")")
+			}
+		}
+	}
+}
@@ -0,0 +1,202 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+struct CssInliner
+{
+	elements: Vec<(Rc<Node>, Vec<(Importance, u32, usize, PropertyDeclaration<Importance>)>)>,
+	element_indices: HashMap<*const Node, usize>,
+	residual_css_rules: Vec<CssRule>,
+}
+
+impl CssInliner
+{
+	fn inline(rc_dom: &mut RcDom, context: &Path) -> Result<(), HtmlError>
+	{
+		let style_elements: Vec<Rc<Node>> = rc_dom.select_all(&parse_css_selector("style:not([media])").unwrap()).collect();
+		
+		let mut stylesheets = Vec::with_capacity(style_elements.len());
+		for style_element in style_elements.iter()
+		{
+			stylesheets.push(CascadeResolver::parse_style_element(style_element, context)?);
+		}
+		
+		let mut this = Self
+		{
+			elements: Vec::new(),
+			element_indices: HashMap::new(),
+			residual_css_rules: Vec::new(),
+		};
+		this.cascade(stylesheets, rc_dom);
+		this.cascade_style_attributes(context)?;
+		this.write_style_attributes();
+		
+		let mut style_elements = style_elements.into_iter();
+		if !this.residual_css_rules.is_empty()
+		{
+			let residual_style_element = style_elements.next().unwrap();
+			let children = residual_style_element.children.borrow().clone();
+			for child in children.iter()
+			{
+				child.remove(rc_dom);
+			}
+			
+			let residual_stylesheet = Stylesheet
+			{
+				rules: CssRules(this.residual_css_rules),
+				source_map_url: None,
+				source_url: None,
+			};
+			residual_style_element.append_text(rc_dom, &residual_stylesheet.to_css_string(false));
+		}
+		
+		for style_element in style_elements
+		{
+			style_element.remove(rc_dom);
+		}
+		
+		Ok(())
+	}
+	
+	// Selectors that match by user interaction or history, or that style pseudo-elements, can not be inlined; neither can at-rules, such as `@media`.
+	fn cascade(&mut self, stylesheets: Vec<Stylesheet>, rc_dom: &RcDom)
+	{
+		let mut selector_set = SelectorSet::default();
+		let mut inlined_style_rules = Vec::new();
+		let mut selector_style_rules = Vec::new();
+		
+		for stylesheet in stylesheets
+		{
+			for css_rule in stylesheet.rules.0
+			{
+				match css_rule
+				{
+					CssRule::Style(style_rule) =>
+					{
+						let (inlined_selectors, residual_selectors): (Vec<OurSelector>, Vec<OurSelector>) = style_rule.selectors.0.into_iter().partition(|selector| !selector.has_pseudo_element() && !Self::uses_a_dynamic_pseudo_class(selector.iter_raw_match_order()));
+						
+						for selector in inlined_selectors
+						{
+							selector_style_rules.push((selector.specificity(), inlined_style_rules.len()));
+							selector_set.insert(selector);
+						}
+						
+						if !residual_selectors.is_empty()
+						{
+							self.residual_css_rules.push(CssRule::Style(StyleRule
+							{
+								selectors: DeduplicatedSelectors(residual_selectors),
+								property_declarations: style_rule.property_declarations.clone(),
+							}));
+						}
+						
+						inlined_style_rules.push(style_rule.property_declarations);
+					}
+					
+					css_rule => self.residual_css_rules.push(css_rule),
+				}
+			}
+		}
+		
+		let selector_set_matches = selector_set.matches(from_ref(rc_dom));
+		for (selector_index, &(specificity, source_order)) in selector_style_rules.iter().enumerate()
+		{
+			for node in selector_set_matches.nodes(selector_index)
+			{
+				let element_index = self.element_index(node);
+				let declarations = &mut self.elements[element_index].1;
+				for property_declaration in inlined_style_rules[source_order].0.iter()
+				{
+					declarations.push((property_declaration.importance, specificity, source_order, property_declaration.clone()));
+				}
+			}
+		}
+	}
+	
+	fn cascade_style_attributes(&mut self, context: &Path) -> Result<(), HtmlError>
+	{
+		let style_attribute_nodes: Vec<Rc<Node>> = self.elements.iter().map(|&(ref node, _)| node.clone()).collect();
+		for node in style_attribute_nodes
+		{
+			if let Some(style_attribute) = node.get_attribute("style")
+			{
+				let property_declarations = match CascadeResolver::parse_style_attribute(&style_attribute)
+				{
+					Some(property_declarations) => property_declarations,
+					None => return Err(HtmlError::InvalidFile(context.to_path_buf(), format!("The style attribute '{}' could not be parsed", style_attribute))),
+				};
+				
+				let element_index = self.element_index(&node);
+				let declarations = &mut self.elements[element_index].1;
+				for property_declaration in property_declarations.0
+				{
+					declarations.push((property_declaration.importance, CascadeResolver::STYLE_ATTRIBUTE_SPECIFICITY, 0, property_declaration));
+				}
+			}
+		}
+		Ok(())
+	}
+	
+	// Sorts declarations into cascade order, so that later declarations of a property win over earlier ones.
+	fn write_style_attributes(&mut self)
+	{
+		for &mut (ref node, ref mut declarations) in self.elements.iter_mut()
+		{
+			declarations.sort_by(|left, right| (left.0, left.1, left.2).cmp(&(right.0, right.1, right.2)));
+			
+			let mut merged: Vec<PropertyDeclaration<Importance>> = Vec::with_capacity(declarations.len());
+			for &(_, _, _, ref property_declaration) in declarations.iter()
+			{
+				merged.retain(|earlier| !(earlier.vendor_prefix == property_declaration.vendor_prefix && earlier.name.eq_ignore_ascii_case(&property_declaration.name)));
+				let mut property_declaration = property_declaration.clone();
+				if let UnparsedPropertyValue::SpecifiedValue(ref mut specified_value) = property_declaration.value
+				{
+					specified_value.originalCss = specified_value.originalCss.trim().to_owned();
+				}
+				merged.push(property_declaration);
+			}
+			
+			let mut style = String::new();
+			PropertyDeclarations(merged).to_css(&mut style).unwrap();
+			
+			node.set_attribute("style", &style);
+		}
+	}
+	
+	#[inline(always)]
+	fn element_index(&mut self, node: &Rc<Node>) -> usize
+	{
+		let elements = &mut self.elements;
+		*self.element_indices.entry(&**node as *const Node).or_insert_with(||
+		{
+			elements.push((node.clone(), Vec::new()));
+			elements.len() - 1
+		})
+	}
+	
+	fn uses_a_dynamic_pseudo_class<'a, I: Iterator<Item=&'a Component<OurSelectorImpl>>>(components: I) -> bool
+	{
+		use ::css::domain::selectors::NonTreeStructuralPseudoClass::*;
+		
+		for component in components
+		{
+			let is_dynamic = match *component
+			{
+				Component::NonTSPseudoClass(ref pseudo_class) => match *pseudo_class
+				{
+					active | focus | focus_within | focusring(_) | fullscreen(_) | hover | link | target | visited => true,
+					any(_, ref selectors) => selectors.0.iter().any(|selector| Self::uses_a_dynamic_pseudo_class(selector.iter_raw_match_order())),
+					_ => false,
+				},
+				Component::Negation(ref components) => Self::uses_a_dynamic_pseudo_class(components.iter()),
+				_ => false,
+			};
+			
+			if is_dynamic
+			{
+				return true;
+			}
+		}
+		false
+	}
+}
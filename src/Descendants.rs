@@ -0,0 +1,42 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An iterator over the descendants of a node, in pre-order (document order), not including the node itself; returned by `NodeExt::descendants()`.
+#[derive(Debug, Clone)]
+pub struct Descendants
+{
+	traverse: Traverse,
+}
+
+impl Iterator for Descendants
+{
+	type Item = Rc<Node>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop
+		{
+			if let Edge::Open(node) = self.traverse.next()?
+			{
+				if !Rc::ptr_eq(&node, &self.traverse.root)
+				{
+					return Some(node)
+				}
+			}
+		}
+	}
+}
+
+impl Descendants
+{
+	#[inline(always)]
+	fn new(node: &Rc<Node>, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Self
+	{
+		Self
+		{
+			traverse: Traverse::new(node, skip_inter_element_whitespace_comment_or_processing_instructions),
+		}
+	}
+}
@@ -0,0 +1,44 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An iterator over the descendants of a node, in post-order (children before their parent), not including the node itself; returned by `NodeExt::descendants_post_order()`.
+/// Useful for removing or replacing nodes, as a node is yielded after all of its descendants.
+#[derive(Debug, Clone)]
+pub struct DescendantsPostOrder
+{
+	traverse: Traverse,
+}
+
+impl Iterator for DescendantsPostOrder
+{
+	type Item = Rc<Node>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop
+		{
+			if let Edge::Close(node) = self.traverse.next()?
+			{
+				if Rc::ptr_eq(&node, &self.traverse.root)
+				{
+					return None
+				}
+				return Some(node)
+			}
+		}
+	}
+}
+
+impl DescendantsPostOrder
+{
+	#[inline(always)]
+	fn new(node: &Rc<Node>, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Self
+	{
+		Self
+		{
+			traverse: Traverse::new(node, skip_inter_element_whitespace_comment_or_processing_instructions),
+		}
+	}
+}
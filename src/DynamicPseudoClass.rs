@@ -0,0 +1,29 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A pseudo-class that depends on user interaction or browser state, and so can not be evaluated from a static HTML DOM.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum DynamicPseudoClass
+{
+	/// `:active`.
+	Active,
+	
+	/// `:focus`, and `:-moz-focusring`.
+	Focus,
+	
+	/// `:focus-within`.
+	FocusWithin,
+	
+	/// `:fullscreen`.
+	Fullscreen,
+	
+	/// `:hover`.
+	Hover,
+	
+	/// `:target`.
+	Target,
+	
+	/// `:visited`; `:link` matches links for which this does not match.
+	Visited,
+}
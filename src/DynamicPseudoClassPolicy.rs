@@ -0,0 +1,67 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// How to match a `DynamicPseudoClass`, such as `:hover`, when matching selectors against a static HTML DOM.
+/// The default, `AlwaysMatch`, is appropriate when deciding if CSS rules might ever apply, eg when purifying a stylesheet.
+#[derive(Clone)]
+pub enum DynamicPseudoClassPolicy
+{
+	/// Every element that could be in the state matches, eg every element for `:hover` and every link for both `:link` and `:visited`.
+	AlwaysMatch,
+	
+	/// No element matches.
+	NeverMatch,
+	
+	/// The callback decides if the element is in the state.
+	Callback(Rc<dyn Fn(&Rc<Node>, DynamicPseudoClass) -> bool>),
+}
+
+impl Debug for DynamicPseudoClassPolicy
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::DynamicPseudoClassPolicy::*;
+		
+		match *self
+		{
+			AlwaysMatch => write!(f, "AlwaysMatch"),
+			NeverMatch => write!(f, "NeverMatch"),
+			Callback(_) => write!(f, "Callback(..)"),
+		}
+	}
+}
+
+impl Default for DynamicPseudoClassPolicy
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		DynamicPseudoClassPolicy::AlwaysMatch
+	}
+}
+
+impl DynamicPseudoClassPolicy
+{
+	/// A policy that uses `callback` to decide if an element is in a state.
+	#[inline(always)]
+	pub fn callback<F: Fn(&Rc<Node>, DynamicPseudoClass) -> bool + 'static>(callback: F) -> Self
+	{
+		DynamicPseudoClassPolicy::Callback(Rc::new(callback))
+	}
+	
+	/// Does `node` match `dynamic_pseudo_class`?
+	#[inline(always)]
+	pub fn matches(&self, node: &Rc<Node>, dynamic_pseudo_class: DynamicPseudoClass) -> bool
+	{
+		use self::DynamicPseudoClassPolicy::*;
+		
+		match *self
+		{
+			AlwaysMatch => true,
+			NeverMatch => false,
+			Callback(ref callback) => callback(node, dynamic_pseudo_class),
+		}
+	}
+}
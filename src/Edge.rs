@@ -0,0 +1,55 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An event of a depth-first traversal; yielded by `Traverse`.
+#[derive(Clone)]
+pub enum Edge
+{
+	/// Entering a node, before any of its children.
+	Open(Rc<Node>),
+	
+	/// Leaving a node, after all of its children.
+	Close(Rc<Node>),
+}
+
+impl Debug for Edge
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::Edge::*;
+		
+		match *self
+		{
+			Open(ref node) =>
+			{
+				f.write_str("Open(")?;
+				node.debug_fmt(f)?;
+			}
+			
+			Close(ref node) =>
+			{
+				f.write_str("Close(")?;
+				node.debug_fmt(f)?;
+			}
+		}
+		f.write_str(")")
+	}
+}
+
+impl Edge
+{
+	/// The node entered or left.
+	#[inline(always)]
+	pub fn node(&self) -> &Rc<Node>
+	{
+		use self::Edge::*;
+		
+		match *self
+		{
+			Open(ref node) => node,
+			Close(ref node) => node,
+		}
+	}
+}
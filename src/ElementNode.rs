@@ -6,6 +6,8 @@
 struct ElementNode
 {
 	node: Rc<Node>,
+	dynamic_pseudo_class_policy: DynamicPseudoClassPolicy,
+	scope_root: Option<Rc<Node>>,
 }
 
 impl Debug for ElementNode
@@ -69,7 +71,7 @@ impl Element for ElementNode
 	#[inline(always)]
 	fn parent_element(&self) -> Option<Self>
 	{
-		self.node.parent().map(|node| Self { node })
+		self.node.parent().map(|node| self.with_node(node))
 	}
 	
 	#[inline(always)]
@@ -164,12 +166,81 @@ impl Element for ElementNode
 	fn match_non_ts_pseudo_class<F: FnMut(&Self, ElementSelectorFlags)>(&self, pc: &<Self::Impl as SelectorImpl>::NonTSPseudoClass, _context: &mut LocalMatchingContext<Self::Impl>, _relevant_link: &RelevantLinkStatus, _flags_setter: &mut F) -> bool
 	{
 		use ::css::domain::selectors::NonTreeStructuralPseudoClass::*;
+		use self::DynamicPseudoClass::*;
 		
 		match *pc
 		{
-			any_link(_) | link | visited => self.is_link(),
+			any(_, ref selectors) => selectors.0.iter().any(|selector| matches(selector, self)),
 			
-			_ => true,
+			any_link(_) => self.is_link(),
+			
+			// Under `AlwaysMatch` a link might be either unvisited or visited, so it matches both `:link` and `:visited`.
+			link => self.is_link() && match self.dynamic_pseudo_class_policy
+			{
+				DynamicPseudoClassPolicy::AlwaysMatch => true,
+				_ => !self.matches_dynamic_pseudo_class(Visited),
+			},
+			
+			visited => self.is_link() && self.matches_dynamic_pseudo_class(Visited),
+			
+			active => self.matches_dynamic_pseudo_class(Active),
+			
+			focus | focusring(_) => self.matches_dynamic_pseudo_class(Focus),
+			
+			focus_within => self.matches_dynamic_pseudo_class(FocusWithin),
+			
+			fullscreen(_) => self.matches_dynamic_pseudo_class(Fullscreen),
+			
+			hover => self.matches_dynamic_pseudo_class(Hover),
+			
+			target => self.matches_dynamic_pseudo_class(Target),
+			
+			checked => self.is_checked(),
+			
+			default => self.is_default(),
+			
+			dir(_, ref text_directionality) => self.directionality() == *text_directionality,
+			
+			disabled => self.can_be_disabled() && self.is_disabled(),
+			
+			enabled => self.can_be_disabled() && !self.is_disabled(),
+			
+			indeterminate => self.is_indeterminate(),
+			
+			in_range => self.is_in_range() == Some(true),
+			
+			out_of_range => self.is_in_range() == Some(false),
+			
+			invalid | ui_invalid(_) => self.is_valid() == Some(false),
+			
+			valid | ui_valid(_) => self.is_valid() == Some(true),
+			
+			lang(ref language_ranges) => language_ranges.matches_language(&self.language()),
+			
+			optional => self.is_required() == Some(false),
+			
+			required => self.is_required() == Some(true),
+			
+			placeholder_shown(_) => self.is_placeholder_shown(),
+			
+			read_only(_) => !self.is_read_write(),
+			
+			read_write(_) => self.is_read_write(),
+			
+			only_whitespace(_) => self.node.children.borrow().iter().all(|child| match child.data
+			{
+				Text { ref contents } => contents.borrow().chars().all(|character| character.is_whitespace()),
+				_ => false,
+			}),
+			
+			first_node(_) => self.node.parent().is_some() && self.node.previous_sibling(true).is_none(),
+			
+			last_node(_) => self.node.parent().is_some() && self.node.next_sibling(true).is_none(),
+			
+			non_zero_border(_) => self.is_only_local(&local_name!("table")) && self.use_attribute_value(&local_name!("border"), |border| border.trim() != "0", false),
+			
+			// `@page` pseudo-classes, and browser-internal states that a static HTML DOM is never in.
+			_ => false,
 		}
 	}
 	
@@ -208,6 +279,16 @@ impl Element for ElementNode
 			return false;
 		}
 		
+		// `:scope`, rewritten by `parse_scoped_css_selector()`; without a scope root, it is the same as `:root`.
+		if id.deref() == SCOPE_IDENTIFIER
+		{
+			return match self.scope_root
+			{
+				None => self.is_root(),
+				Some(ref scope_root) => Rc::ptr_eq(scope_root, &self.node),
+			}
+		}
+		
 		self.use_attribute_value(&local_name!("id"), |id_attribute_value| Self::case_sensitive_equality(case_sensitivity, id_attribute_value, id.deref()), false)
 	}
 	
@@ -248,6 +329,561 @@ impl Element for ElementNode
 
 impl ElementNode
 {
+	#[inline(always)]
+	fn new(node: Rc<Node>) -> Self
+	{
+		Self
+		{
+			node,
+			dynamic_pseudo_class_policy: DynamicPseudoClassPolicy::default(),
+			scope_root: None,
+		}
+	}
+	
+	// Another element, such as a parent, matched with the same policy.
+	#[inline(always)]
+	fn with_node(&self, node: Rc<Node>) -> Self
+	{
+		Self
+		{
+			node,
+			dynamic_pseudo_class_policy: self.dynamic_pseudo_class_policy.clone(),
+			scope_root: self.scope_root.clone(),
+		}
+	}
+	
+	#[inline(always)]
+	fn matches_dynamic_pseudo_class(&self, dynamic_pseudo_class: DynamicPseudoClass) -> bool
+	{
+		self.dynamic_pseudo_class_policy.matches(&self.node, dynamic_pseudo_class)
+	}
+	
+	#[inline(always)]
+	fn has_attribute(&self, attribute_name: &LocalName) -> bool
+	{
+		self.use_attribute_value(attribute_name, |_| true, false)
+	}
+	
+	// The `type` of an `<input>`, lower cased and defaulting to `text`.
+	#[inline(always)]
+	fn input_type(&self) -> String
+	{
+		self.use_attribute_value(&local_name!("type"), |type_| type_.trim().to_ascii_lowercase(), "text".to_owned())
+	}
+	
+	#[inline(always)]
+	fn is_input_of_type(&self, types: &[&str]) -> bool
+	{
+		self.is_only_local(&local_name!("input")) && types.contains(&&self.input_type()[..])
+	}
+	
+	// The nearest ancestor `<form>`, or otherwise the root, within which radio button groups and default buttons are found.
+	#[inline(always)]
+	fn form_owner_or_root(&self) -> ElementNode
+	{
+		let mut root = self.node.clone();
+		for ancestor in self.node.ancestors()
+		{
+			if ancestor.is_only_local(&local_name!("form"))
+			{
+				return self.with_node(ancestor);
+			}
+			root = ancestor;
+		}
+		self.with_node(root)
+	}
+	
+	// Is any radio button in the same group as this one, including this one, checked?
+	#[inline(always)]
+	fn is_radio_button_group_checked(&self) -> bool
+	{
+		let name = self.use_attribute_value(&local_name!("name"), |name| name.to_owned(), String::new());
+		if name.is_empty()
+		{
+			return self.has_attribute(&local_name!("checked"));
+		}
+		
+		self.form_owner_or_root().node.descendants(false).map(|descendant| self.with_node(descendant)).any(|descendant| descendant.has_attribute(&local_name!("checked")) && descendant.is_input_of_type(&["radio"]) && descendant.use_attribute_value(&local_name!("name"), |other_name| other_name == name, false))
+	}
+	
+	// The options of a `<select>`, in tree order.
+	#[inline(always)]
+	fn options(&self) -> Filter<Descendants, fn(&Rc<Node>) -> bool>
+	{
+		#[inline(always)]
+		fn is_option(node: &Rc<Node>) -> bool
+		{
+			node.is_only_local(&local_name!("option"))
+		}
+		
+		self.node.descendants(false).filter(is_option)
+	}
+	
+	// Is this a `<select>` that shows a drop-down list, and so always has a selected option?
+	#[inline(always)]
+	fn is_select_with_drop_down_list(&self) -> bool
+	{
+		let size = self.use_attribute_value(&local_name!("size"), |size| size.trim().parse::<u32>().unwrap_or(1), 1);
+		self.is_only_local(&local_name!("select")) && !self.has_attribute(&local_name!("multiple")) && size <= 1
+	}
+	
+	// The selectedness of an `<option>`; if no option in a drop-down `<select>` is explicitly selected, the first enabled one is.
+	fn is_selected_option(&self) -> bool
+	{
+		if self.has_attribute(&local_name!("selected"))
+		{
+			return true;
+		}
+		
+		match self.node.ancestors().find(|ancestor| ancestor.is_only_local(&local_name!("select"))).map(|select| self.with_node(select))
+		{
+			Some(ref select) if select.is_select_with_drop_down_list() =>
+			{
+				!select.options().any(|option| select.with_node(option).has_attribute(&local_name!("selected"))) && select.options().map(|option| select.with_node(option)).find(|option| !option.is_disabled()).map(|option| Rc::ptr_eq(&option.node, &self.node)).unwrap_or(false)
+			}
+			
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn is_checked(&self) -> bool
+	{
+		if self.is_input_of_type(&["checkbox", "radio"])
+		{
+			self.has_attribute(&local_name!("checked"))
+		}
+		else if self.is_only_local(&local_name!("option"))
+		{
+			self.is_selected_option()
+		}
+		else
+		{
+			false
+		}
+	}
+	
+	#[inline(always)]
+	fn is_submit_button(&self) -> bool
+	{
+		if self.is_only_local(&local_name!("button"))
+		{
+			match &self.use_attribute_value(&local_name!("type"), |type_| type_.trim().to_ascii_lowercase(), "submit".to_owned())[..]
+			{
+				"button" | "reset" => false,
+				_ => true,
+			}
+		}
+		else
+		{
+			self.is_input_of_type(&["image", "submit"])
+		}
+	}
+	
+	fn is_default(&self) -> bool
+	{
+		if self.is_input_of_type(&["checkbox", "radio"])
+		{
+			self.has_attribute(&local_name!("checked"))
+		}
+		else if self.is_only_local(&local_name!("option"))
+		{
+			self.has_attribute(&local_name!("selected"))
+		}
+		else if self.is_submit_button()
+		{
+			let form_owner = self.form_owner_or_root();
+			if !form_owner.is_only_local(&local_name!("form"))
+			{
+				return false;
+			}
+			
+			form_owner.node.descendants(false).map(|descendant| form_owner.with_node(descendant)).find(|descendant| descendant.is_submit_button()).map(|default_button| Rc::ptr_eq(&default_button.node, &self.node)).unwrap_or(false)
+		}
+		else
+		{
+			false
+		}
+	}
+	
+	#[inline(always)]
+	fn can_be_disabled(&self) -> bool
+	{
+		self.is_only_local_of(&[local_name!("button"), local_name!("fieldset"), local_name!("input"), local_name!("optgroup"), local_name!("option"), local_name!("select"), local_name!("textarea")])
+	}
+	
+	// Is this element [actually disabled](https://html.spec.whatwg.org/multipage/semantics-other.html#concept-element-disabled)?
+	fn is_disabled(&self) -> bool
+	{
+		if !self.can_be_disabled()
+		{
+			return false;
+		}
+		
+		if self.has_attribute(&local_name!("disabled"))
+		{
+			return true;
+		}
+		
+		if self.is_only_local_of(&[local_name!("optgroup"), local_name!("option")])
+		{
+			return self.is_only_local(&local_name!("option")) && self.parent_element().map(|parent| parent.is_only_local(&local_name!("optgroup")) && parent.has_attribute(&local_name!("disabled"))).unwrap_or(false);
+		}
+		
+		// A form control is disabled by an ancestor `<fieldset disabled>`, unless it is within that fieldset's first `<legend>`.
+		let mut child_of_ancestor = self.clone();
+		for ancestor in self.node.ancestors().map(|ancestor| self.with_node(ancestor))
+		{
+			if ancestor.is_only_local(&local_name!("fieldset")) && ancestor.has_attribute(&local_name!("disabled"))
+			{
+				if !child_of_ancestor.is_first_legend()
+				{
+					return true;
+				}
+			}
+			child_of_ancestor = ancestor;
+		}
+		false
+	}
+	
+	#[inline(always)]
+	fn is_first_legend(&self) -> bool
+	{
+		if !self.is_only_local(&local_name!("legend"))
+		{
+			return false;
+		}
+		
+		let mut previous = self.prev_sibling_element();
+		while let Some(element) = previous
+		{
+			if element.is_only_local(&local_name!("legend"))
+			{
+				return false;
+			}
+			previous = element.prev_sibling_element();
+		}
+		true
+	}
+	
+	fn is_indeterminate(&self) -> bool
+	{
+		if self.is_input_of_type(&["radio"])
+		{
+			!self.is_radio_button_group_checked()
+		}
+		else if self.is_only_local(&local_name!("progress"))
+		{
+			!self.has_attribute(&local_name!("value"))
+		}
+		else
+		{
+			false
+		}
+	}
+	
+	// Some(true) if this element has range limitations and its value is within them, Some(false) if it is not, and None if it has no range limitations.
+	//noinspection SpellCheckingInspection
+	fn is_in_range(&self) -> Option<bool>
+	{
+		if !self.is_input_of_type(&["date", "datetime-local", "month", "number", "range", "time", "week"]) || self.is_disabled() || self.has_attribute(&local_name!("readonly"))
+		{
+			return None;
+		}
+		
+		let input_type = self.input_type();
+		let attribute = |attribute_name: LocalName| self.use_attribute_value(&attribute_name, |value| Some(value.trim().to_owned()), None).and_then(|value| if value.is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(value)
+		});
+		
+		let (minimum, maximum) = if input_type == "range"
+		{
+			(attribute(local_name!("min")).or(Some("0".to_owned())), attribute(local_name!("max")).or(Some("100".to_owned())))
+		}
+		else
+		{
+			(attribute(local_name!("min")), attribute(local_name!("max")))
+		};
+		
+		if minimum.is_none() && maximum.is_none()
+		{
+			return None;
+		}
+		
+		let value = match attribute(local_name!("value"))
+		{
+			None => return Some(true),
+			Some(value) => value,
+		};
+		
+		// Dates and times in their valid formats compare lexically.
+		let is_less_than = |left: &str, right: &str| match (left.parse::<f64>(), right.parse::<f64>())
+		{
+			(Ok(left), Ok(right)) if input_type == "number" || input_type == "range" => left < right,
+			_ => left < right,
+		};
+		
+		let is_underflow = minimum.map(|minimum| is_less_than(&value, &minimum)).unwrap_or(false);
+		let is_overflow = maximum.map(|maximum| is_less_than(&maximum, &value)).unwrap_or(false);
+		Some(!is_underflow && !is_overflow)
+	}
+	
+	// Some(true) if this element is required, Some(false) if it is optional, and None if it is neither.
+	#[inline(always)]
+	fn is_required(&self) -> Option<bool>
+	{
+		let can_be_required = if self.is_only_local(&local_name!("input"))
+		{
+			!self.is_input_of_type(&["button", "color", "hidden", "image", "range", "reset", "submit"])
+		}
+		else
+		{
+			self.is_only_local_of(&[local_name!("select"), local_name!("textarea")])
+		};
+		
+		if can_be_required
+		{
+			Some(self.has_attribute(&local_name!("required")))
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	// Some(true) if this element is valid, Some(false) if it is invalid and None if it is not subject to constraint validation.
+	// Only constraints that can be known from the HTML are checked: missing values and range limitations.
+	//noinspection SpellCheckingInspection
+	fn is_valid(&self) -> Option<bool>
+	{
+		if self.is_only_local_of(&[local_name!("fieldset"), local_name!("form")])
+		{
+			return Some(self.node.descendants(false).all(|descendant| self.with_node(descendant).is_valid() != Some(false)));
+		}
+		
+		let is_candidate = if self.is_only_local(&local_name!("input"))
+		{
+			!self.is_input_of_type(&["button", "hidden", "image", "reset", "submit"]) && !self.has_attribute(&local_name!("readonly"))
+		}
+		else if self.is_only_local(&local_name!("textarea"))
+		{
+			!self.has_attribute(&local_name!("readonly"))
+		}
+		else
+		{
+			self.is_only_local(&local_name!("select"))
+		};
+		
+		if !is_candidate || self.is_disabled() || self.node.ancestors().any(|ancestor| ancestor.is_only_local(&local_name!("datalist")))
+		{
+			return None;
+		}
+		
+		let is_value_missing = self.is_required() == Some(true) && if self.is_input_of_type(&["checkbox"])
+		{
+			!self.has_attribute(&local_name!("checked"))
+		}
+		else if self.is_input_of_type(&["radio"])
+		{
+			!self.is_radio_button_group_checked()
+		}
+		else if self.is_input_of_type(&["file"])
+		{
+			true
+		}
+		else if self.is_only_local(&local_name!("textarea"))
+		{
+			self.node.children.borrow().iter().all(|child| match child.data
+			{
+				Text { ref contents } => contents.borrow().is_empty(),
+				_ => true,
+			})
+		}
+		else if self.is_only_local(&local_name!("select"))
+		{
+			match self.options().map(|option| self.with_node(option)).find(|option| option.is_selected_option())
+			{
+				None => true,
+				
+				// The placeholder label option.
+				Some(selected_option) => self.is_select_with_drop_down_list() && self.options().next().map(|first_option| Rc::ptr_eq(&selected_option.node, &first_option)).unwrap_or(false) && selected_option.parent_element().map(|parent| Rc::ptr_eq(&parent.node, &self.node)).unwrap_or(false) && selected_option.use_attribute_value(&local_name!("value"), |value| value.is_empty(), selected_option.node.children.borrow().is_empty()),
+			}
+		}
+		else
+		{
+			self.use_attribute_value(&local_name!("value"), |value| value.is_empty(), true)
+		};
+		
+		Some(!is_value_missing && self.is_in_range() != Some(false))
+	}
+	
+	// The language of this element from the nearest `lang` or `xml:lang` attribute, or an empty string if unknown.
+	#[inline(always)]
+	fn language(&self) -> String
+	{
+		let mut element = Some(self.clone());
+		while let Some(current) = element
+		{
+			if let NodeData::Element { ref attrs, .. } = current.node.data
+			{
+				let language = attrs.borrow().iter().find(|attribute| attribute.name.local == local_name!("lang") && (attribute.name.ns == ns!() || attribute.name.ns == ns!(xml))).map(|attribute| attribute.value.trim().to_owned());
+				if let Some(language) = language
+				{
+					return language;
+				}
+			}
+			element = current.parent_element();
+		}
+		String::new()
+	}
+	
+	// The [directionality](https://html.spec.whatwg.org/multipage/dom.html#the-directionality) of this element.
+	fn directionality(&self) -> TextDirectionality
+	{
+		use self::TextDirectionality::*;
+		
+		let dir = self.use_attribute_value(&local_name!("dir"), |dir| Some(dir.trim().to_ascii_lowercase()), None);
+		match dir.as_ref().map(|dir| &dir[..])
+		{
+			Some("ltr") => ltr,
+			
+			Some("rtl") => rtl,
+			
+			Some("auto") => self.auto_directionality(),
+			
+			_ => if self.is_only_local(&local_name!("bdi"))
+			{
+				self.auto_directionality()
+			}
+			else if self.is_input_of_type(&["tel"])
+			{
+				ltr
+			}
+			else
+			{
+				match self.parent_element()
+				{
+					Some(parent) => parent.directionality(),
+					None => ltr,
+				}
+			},
+		}
+	}
+	
+	// The direction of the first character with a strong direction in the value or descendant text, ignoring `<bdi>`, `<script>`, `<style>`, `<textarea>` and elements with a `dir` attribute.
+	fn auto_directionality(&self) -> TextDirectionality
+	{
+		fn first_strong_direction(text: &str) -> Option<TextDirectionality>
+		{
+			for character in text.chars()
+			{
+				match character as u32
+				{
+					0x0590 ..= 0x08FF | 0xFB1D ..= 0xFDFF | 0xFE70 ..= 0xFEFF | 0x10800 ..= 0x10FFF | 0x1E800 ..= 0x1EFFF => return Some(TextDirectionality::rtl),
+					_ => if character.is_alphabetic()
+					{
+						return Some(TextDirectionality::ltr)
+					},
+				}
+			}
+			None
+		}
+		
+		fn descendant_text_direction(node: &Rc<Node>) -> Option<TextDirectionality>
+		{
+			for child in node.children.borrow().iter()
+			{
+				let direction = match child.data
+				{
+					Text { ref contents } => first_strong_direction(&contents.borrow()),
+					
+					NodeData::Element { ref name, ref attrs, .. } =>
+					{
+						let is_ignored = name.is_only_local_of(&[local_name!("bdi"), local_name!("script"), local_name!("style"), local_name!("textarea")]) || attrs.borrow().iter().any(|attribute| attribute.name.is_only_local(&local_name!("dir")));
+						if is_ignored
+						{
+							None
+						}
+						else
+						{
+							descendant_text_direction(child)
+						}
+					}
+					
+					_ => None,
+				};
+				
+				if direction.is_some()
+				{
+					return direction;
+				}
+			}
+			None
+		}
+		
+		let direction = if self.is_only_local_of(&[local_name!("input"), local_name!("textarea")])
+		{
+			self.use_attribute_value(&local_name!("value"), first_strong_direction, None).or_else(|| descendant_text_direction(&self.node))
+		}
+		else
+		{
+			descendant_text_direction(&self.node)
+		};
+		direction.unwrap_or(TextDirectionality::ltr)
+	}
+	
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	fn is_placeholder_shown(&self) -> bool
+	{
+		if !self.has_attribute(&local_name!("placeholder"))
+		{
+			return false;
+		}
+		
+		if self.is_input_of_type(&["email", "number", "password", "search", "tel", "text", "url"])
+		{
+			self.use_attribute_value(&local_name!("value"), |value| value.is_empty(), true)
+		}
+		else if self.is_only_local(&local_name!("textarea"))
+		{
+			self.node.children.borrow().is_empty()
+		}
+		else
+		{
+			false
+		}
+	}
+	
+	// Is this element [mutable](https://html.spec.whatwg.org/multipage/semantics-other.html#selector-read-write) by the user?
+	//noinspection SpellCheckingInspection
+	fn is_read_write(&self) -> bool
+	{
+		if self.is_input_of_type(&["date", "datetime-local", "email", "month", "number", "password", "search", "tel", "text", "time", "url", "week"]) || self.is_only_local(&local_name!("textarea"))
+		{
+			return !self.has_attribute(&local_name!("readonly")) && !self.is_disabled();
+		}
+		
+		let mut element = Some(self.clone());
+		while let Some(current) = element
+		{
+			let content_editable = current.use_attribute_value(&local_name!("contenteditable"), |content_editable| Some(content_editable.trim().to_ascii_lowercase()), None);
+			match content_editable.as_ref().map(|content_editable| &content_editable[..])
+			{
+				Some("") | Some("true") | Some("plaintext-only") => return true,
+				Some("false") => return false,
+				_ => (),
+			}
+			element = current.parent_element();
+		}
+		false
+	}
+	
 	#[inline(always)]
 	fn use_attribute_value<R, AttributeValueUser: Fn(&str) -> R>(&self, attribute_name: &LocalName, attribute_value_user: AttributeValueUser, default: R) -> R
 	{
@@ -276,7 +912,7 @@ impl ElementNode
 	fn first_or_last_child_element(&self, last: bool) -> Option<Self>
 	{
 		#[inline(always)]
-		fn iterate<'a, I: Iterator<Item=&'a std::rc::Rc<Node>>>(mut children_iterator: I) -> Option<ElementNode>
+		fn iterate<'a, I: Iterator<Item=&'a std::rc::Rc<Node>>>(this: &ElementNode, mut children_iterator: I) -> Option<ElementNode>
 		{
 			let mut child_node;
 			while
@@ -288,10 +924,7 @@ impl ElementNode
 				let possible = child_node.unwrap();
 				match possible.data
 				{
-					NodeData::Element { .. } => return Some(ElementNode
-					{
-						node: possible.clone(),
-					}),
+					NodeData::Element { .. } => return Some(this.with_node(possible.clone())),
 					
 					_ => (),
 				}
@@ -303,11 +936,11 @@ impl ElementNode
 		let iterator = borrowed.iter();
 		if last
 		{
-			iterate(iterator.rev())
+			iterate(self, iterator.rev())
 		}
 		else
 		{
-			iterate(iterator)
+			iterate(self, iterator)
 		}
 		
 	}
@@ -330,10 +963,7 @@ impl ElementNode
 				{
 					NodeData::Element { .. } =>
 					{
-						previous_sibling = Some(ElementNode
-						{
-							node: current_sibling.clone(),
-						});
+						previous_sibling = Some(this.with_node(current_sibling.clone()));
 					}
 					
 					_ => (),
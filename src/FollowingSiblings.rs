@@ -0,0 +1,55 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An iterator over the siblings after a node, nearest first; returned by `NodeExt::following_siblings()`.
+#[derive(Clone)]
+pub struct FollowingSiblings
+{
+	parent: Option<Rc<Node>>,
+	next_index: usize,
+	skip_inter_element_whitespace_comment_or_processing_instructions: bool,
+}
+
+impl Debug for FollowingSiblings
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "FollowingSiblings {{ next_index: {}, skip_inter_element_whitespace_comment_or_processing_instructions: {} }}", self.next_index, self.skip_inter_element_whitespace_comment_or_processing_instructions)
+	}
+}
+
+impl Iterator for FollowingSiblings
+{
+	type Item = Rc<Node>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop
+		{
+			let sibling = self.parent.as_ref()?.children.borrow().get(self.next_index).cloned()?;
+			self.next_index += 1;
+			if !(self.skip_inter_element_whitespace_comment_or_processing_instructions && sibling.is_inter_element_whitespace_comment_or_processing_instruction())
+			{
+				return Some(sibling)
+			}
+		}
+	}
+}
+
+impl FollowingSiblings
+{
+	#[inline(always)]
+	fn new(node: &Rc<Node>, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Self
+	{
+		let (parent, index) = node._parent_and_index();
+		Self
+		{
+			parent,
+			next_index: index + 1,
+			skip_inter_element_whitespace_comment_or_processing_instructions,
+		}
+	}
+}
@@ -23,5 +23,12 @@ quick_error!
 			description(&reason)
 			display("The file {:?} can not be used because: {}", path, reason)
 		}
+		
+		/// A HTML file, when parsed, had parse errors; all of them are in `diagnostics`.
+		ParseErrors(path: PathBuf, diagnostics: Vec<ParseDiagnostic>)
+		{
+			description("HTML parsed with errors")
+			display("The file {:?} can not be used because it parsed with errors:\n{}", path, ParseDiagnostic::compiler_style_report(path, diagnostics))
+		}
 	}
 }
@@ -0,0 +1,114 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Options controlling how HTML is parsed.
+/// The default is what the `RcDomExt` constructors that do not take options use: scripting enabled, exact errors, not an iframe srcdoc document, no quirks, DOCTYPE retained and a leading byte order mark discarded.
+/// Use the `with_` methods to change individual options, eg `HtmlParseOptions::default().with_scripting_enabled(false)`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct HtmlParseOptions
+{
+	scripting_enabled: bool,
+	iframe_srcdoc: bool,
+	quirks_mode: QuirksMode,
+	exact_errors: bool,
+	drop_doctype: bool,
+	discard_bom: bool,
+}
+
+impl Default for HtmlParseOptions
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			scripting_enabled: true,
+			iframe_srcdoc: false,
+			quirks_mode: QuirksMode::NoQuirks,
+			exact_errors: true,
+			drop_doctype: false,
+			discard_bom: true,
+		}
+	}
+}
+
+impl HtmlParseOptions
+{
+	/// Is scripting enabled?
+	/// If it is, the content of a `<noscript>` element is parsed as raw text; if not, it is parsed as markup, ie as a browser with JavaScript disabled would.
+	#[inline(always)]
+	pub fn with_scripting_enabled(mut self, scripting_enabled: bool) -> Self
+	{
+		self.scripting_enabled = scripting_enabled;
+		self
+	}
+	
+	/// Is this the content of an `<iframe srcdoc>` attribute?
+	/// If it is, a missing DOCTYPE is not an error and does not cause quirks mode.
+	#[inline(always)]
+	pub fn with_iframe_srcdoc(mut self, iframe_srcdoc: bool) -> Self
+	{
+		self.iframe_srcdoc = iframe_srcdoc;
+		self
+	}
+	
+	/// The quirks mode to start parsing in.
+	/// A DOCTYPE in the document still changes this.
+	#[inline(always)]
+	pub fn with_quirks_mode(mut self, quirks_mode: QuirksMode) -> Self
+	{
+		self.quirks_mode = quirks_mode;
+		self
+	}
+	
+	/// Report all parse errors described in the HTML 5 specification with detailed messages?
+	/// Turning this off is faster but some parse errors are reported more vaguely.
+	#[inline(always)]
+	pub fn with_exact_errors(mut self, exact_errors: bool) -> Self
+	{
+		self.exact_errors = exact_errors;
+		self
+	}
+	
+	/// Drop the DOCTYPE, if any, from the parsed DOM?
+	#[inline(always)]
+	pub fn with_drop_doctype(mut self, drop_doctype: bool) -> Self
+	{
+		self.drop_doctype = drop_doctype;
+		self
+	}
+	
+	/// Discard a leading `U+FEFF BYTE ORDER MARK`?
+	#[inline(always)]
+	pub fn with_discard_bom(mut self, discard_bom: bool) -> Self
+	{
+		self.discard_bom = discard_bom;
+		self
+	}
+	
+	#[inline(always)]
+	fn to_parse_opts(&self) -> ParseOpts
+	{
+		ParseOpts
+		{
+			tokenizer: TokenizerOpts
+			{
+				exact_errors: self.exact_errors,
+				discard_bom: self.discard_bom,
+				profile: false,
+				initial_state: None,
+				last_start_tag_name: None,
+			},
+			tree_builder: TreeBuilderOpts
+			{
+				exact_errors: self.exact_errors,
+				scripting_enabled: self.scripting_enabled,
+				iframe_srcdoc: self.iframe_srcdoc,
+				drop_doctype: self.drop_doctype,
+				ignore_missing_rules: false,
+				quirks_mode: self.quirks_mode,
+			},
+		}
+	}
+}
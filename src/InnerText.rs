@@ -0,0 +1,211 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// An approximation of `HTMLElement.innerText`, ie text as rendered, without a layout engine.
+struct InnerText
+{
+	text: String,
+	pending_space: bool,
+	pending_line_breaks: usize,
+}
+
+impl InnerText
+{
+	fn of(root: &Rc<Node>) -> String
+	{
+		if Self::is_hidden(root)
+		{
+			return root.text_content()
+		}
+		
+		let mut this = Self
+		{
+			text: String::new(),
+			pending_space: false,
+			pending_line_breaks: 0,
+		};
+		
+		let mut hidden: Option<Rc<Node>> = None;
+		let mut whitespace_preserving_depth = 0;
+		for edge in root.traverse(false)
+		{
+			if let Some(hidden_node) = hidden.take()
+			{
+				match edge
+				{
+					Edge::Close(ref node) if Rc::ptr_eq(node, &hidden_node) => (),
+					_ => hidden = Some(hidden_node),
+				}
+				continue
+			}
+			
+			match edge
+			{
+				Edge::Open(node) => match node.data
+				{
+					NodeData::Text { ref contents } => if whitespace_preserving_depth == 0
+					{
+						this.push_collapsible(&contents.borrow())
+					}
+					else
+					{
+						this.push_preserved(&contents.borrow())
+					},
+					
+					NodeData::Element { ref name, .. } =>
+					{
+						if !Rc::ptr_eq(&node, root) && Self::is_hidden(&node)
+						{
+							hidden = Some(node.clone());
+							continue
+						}
+						
+						if !name.can_collapse_whitespace()
+						{
+							whitespace_preserving_depth += 1;
+						}
+						
+						if name.is_only_local(&local_name!("br"))
+						{
+							this.push_preserved("\n");
+						}
+						
+						this.push_line_breaks(Self::required_line_breaks(name));
+					}
+					
+					_ => (),
+				},
+				
+				Edge::Close(node) => if let NodeData::Element { ref name, .. } = node.data
+				{
+					if !name.can_collapse_whitespace()
+					{
+						whitespace_preserving_depth -= 1;
+					}
+					
+					if name.is_only_local(&local_name!("td")) || name.is_only_local(&local_name!("th"))
+					{
+						if node.following_siblings(true).next().is_some()
+						{
+							this.push_preserved("\t");
+						}
+					}
+					
+					this.push_line_breaks(Self::required_line_breaks(name));
+				},
+			}
+		}
+		
+		this.text
+	}
+	
+	// Elements that are not rendered, by default or because of a `hidden` attribute or an inline `display: none`; scripting is assumed to be enabled, so `<noscript>` is not rendered.
+	fn is_hidden(node: &Rc<Node>) -> bool
+	{
+		match node.data
+		{
+			NodeData::Element { ref name, .. } =>
+			{
+				let never_rendered = name.is_unprefixed_and_html_namespace_or_none() && match name.local
+				{
+					local_name!("area") | local_name!("base") | local_name!("basefont") | local_name!("datalist") | local_name!("head") | local_name!("link") | local_name!("meta") | local_name!("noembed") | local_name!("noframes") | local_name!("noscript") | local_name!("param") | local_name!("rp") | local_name!("script") | local_name!("style") | local_name!("template") | local_name!("title") => true,
+					
+					local_name!("input") => node.get_attribute("type").map(|type_| type_.eq_ignore_ascii_case("hidden")).unwrap_or(false),
+					
+					_ => false,
+				};
+				
+				never_rendered || node.has_attribute("hidden") || node.get_attribute("style").map(|style| style.chars().filter(|character| !is_space_character(*character)).collect::<String>().to_ascii_lowercase().contains("display:none")).unwrap_or(false)
+			}
+			
+			_ => false,
+		}
+	}
+	
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	fn required_line_breaks(name: &QualName) -> usize
+	{
+		if !name.is_unprefixed_and_html_namespace_or_none()
+		{
+			return 0
+		}
+		
+		match name.local
+		{
+			local_name!("p") => 2,
+			
+			local_name!("address") | local_name!("article") | local_name!("aside") | local_name!("blockquote") | local_name!("body") | local_name!("caption") | local_name!("center") | local_name!("dd") | local_name!("details") | local_name!("dialog") | local_name!("dir") | local_name!("div") | local_name!("dl") | local_name!("dt") | local_name!("fieldset") | local_name!("figcaption") | local_name!("figure") | local_name!("footer") | local_name!("form") | local_name!("h1") | local_name!("h2") | local_name!("h3") | local_name!("h4") | local_name!("h5") | local_name!("h6") | local_name!("header") | local_name!("hgroup") | local_name!("hr") | local_name!("html") | local_name!("legend") | local_name!("li") | local_name!("listing") | local_name!("main") | local_name!("menu") | local_name!("nav") | local_name!("ol") | local_name!("plaintext") | local_name!("pre") | local_name!("section") | local_name!("summary") | local_name!("table") | local_name!("tbody") | local_name!("tfoot") | local_name!("thead") | local_name!("tr") | local_name!("ul") | local_name!("xmp") => 1,
+			
+			_ => 0,
+		}
+	}
+	
+	// Line breaks at the start or end are removed; a run of line breaks is replaced by the most required.
+	#[inline(always)]
+	fn push_line_breaks(&mut self, count: usize)
+	{
+		if count != 0
+		{
+			self.pending_space = false;
+			if count > self.pending_line_breaks
+			{
+				self.pending_line_breaks = count;
+			}
+		}
+	}
+	
+	// Runs of whitespace collapse to a single space, and whitespace at the start of a line is removed.
+	#[inline(always)]
+	fn push_collapsible(&mut self, text: &str)
+	{
+		for character in text.chars()
+		{
+			if is_space_character(character)
+			{
+				if !(self.text.is_empty() || self.text.ends_with('\n') || self.pending_line_breaks != 0)
+				{
+					self.pending_space = true;
+				}
+			}
+			else
+			{
+				self.push_pending();
+				self.text.push(character);
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn push_preserved(&mut self, text: &str)
+	{
+		if !text.is_empty()
+		{
+			self.push_pending();
+			self.text.push_str(text);
+		}
+	}
+	
+	#[inline(always)]
+	fn push_pending(&mut self)
+	{
+		if self.pending_line_breaks != 0
+		{
+			if !self.text.is_empty()
+			{
+				for _ in 0 .. self.pending_line_breaks
+				{
+					self.text.push('\n');
+				}
+			}
+			self.pending_line_breaks = 0;
+		}
+		
+		if self.pending_space
+		{
+			self.text.push(' ');
+			self.pending_space = false;
+		}
+	}
+}
@@ -0,0 +1,54 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A class that is empty or contains whitespace, and so can not be one of the classes of a `ClassList`; returned by the `ClassList` methods that change classes.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct InvalidClass
+{
+	/// The class, as given.
+	pub class: String,
+}
+
+impl Display for InvalidClass
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "The class '{}' is empty or contains whitespace", self.class)
+	}
+}
+
+impl Error for InvalidClass
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"invalid class"
+	}
+}
+
+impl InvalidClass
+{
+	#[inline(always)]
+	fn is_valid(class: &str) -> bool
+	{
+		!class.is_empty() && !class.contains(SELECTOR_WHITESPACE)
+	}
+	
+	#[inline(always)]
+	fn validate(class: &str) -> Result<(), Self>
+	{
+		if Self::is_valid(class)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(Self
+			{
+				class: class.to_owned(),
+			})
+		}
+	}
+}
@@ -0,0 +1,45 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A CSS selector that could not be parsed; returned by the `Selectable` methods that take a selector as a string.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct InvalidSelector
+{
+	/// The selector, as given.
+	pub selector: String,
+	
+	/// Why it could not be parsed.
+	pub reason: String,
+}
+
+impl Display for InvalidSelector
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "The CSS selector '{}' could not be parsed because: {}", self.selector, self.reason)
+	}
+}
+
+impl Error for InvalidSelector
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"invalid CSS selector"
+	}
+}
+
+impl InvalidSelector
+{
+	#[inline(always)]
+	fn parse(selector: &str) -> Result<OurSelector, Self>
+	{
+		parse_css_selector(selector).map_err(|error| Self
+		{
+			selector: selector.to_owned(),
+			reason: format!("{:?}", error),
+		})
+	}
+}
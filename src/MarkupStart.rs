@@ -0,0 +1,16 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+#[derive(Debug, Clone)]
+struct MarkupStart
+{
+	kind: MarkupStartKind,
+	
+	// Where the opening `<` is.
+	line: u64,
+	column: u64,
+	
+	// The line of the closing `>`; this is the line html5ever's tokenizer is on when it emits the token.
+	end_line: u64,
+}
@@ -0,0 +1,347 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// Where each start tag, comment and DOCTYPE in HTML source starts.
+// html5ever's tokenizer only reports the line it is on when it emits a token, ie that of the closing `>`, so the source is scanned, far more simply than by the tokenizer, to find where each token starts; `take()` then matches each node the tree builder creates with the token it was created from.
+#[derive(Debug, Default)]
+struct MarkupStarts
+{
+	starts: Vec<MarkupStart>,
+	taken: Vec<bool>,
+	next: usize,
+}
+
+impl MarkupStarts
+{
+	fn scan(source: &[u8], options: &HtmlParseOptions) -> Self
+	{
+		use self::MarkupStartKind::*;
+		
+		struct Cursor
+		{
+			characters: Vec<char>,
+			index: usize,
+			line: u64,
+			column: u64,
+		}
+		
+		impl Cursor
+		{
+			#[inline(always)]
+			fn is_at_end(&self) -> bool
+			{
+				self.index >= self.characters.len()
+			}
+			
+			#[inline(always)]
+			fn peek(&self, offset: usize) -> Option<char>
+			{
+				self.characters.get(self.index + offset).cloned()
+			}
+			
+			#[inline(always)]
+			fn starts_with_ignoring_ascii_case(&self, prefix: &str) -> bool
+			{
+				prefix.chars().enumerate().all(|(offset, character)| self.peek(offset).map(|peeked| peeked.eq_ignore_ascii_case(&character)).unwrap_or(false))
+			}
+			
+			#[inline(always)]
+			fn is_at_whitespace(&self) -> bool
+			{
+				self.peek(0).map(|character| character.is_ascii_whitespace()).unwrap_or(false)
+			}
+			
+			fn advance(&mut self, count: usize)
+			{
+				for _ in 0 .. count
+				{
+					match self.peek(0)
+					{
+						None => return,
+						
+						// Like html5ever's tokenizer, treat `\r\n` as a single line break.
+						Some('\r') if self.peek(1) == Some('\n') => (),
+						
+						Some('\r') | Some('\n') =>
+						{
+							self.line += 1;
+							self.column = 1;
+						}
+						
+						Some(_) => self.column += 1,
+					}
+					self.index += 1;
+				}
+			}
+			
+			fn advance_past(&mut self, terminator: &str)
+			{
+				while !self.is_at_end() && !self.starts_with_ignoring_ascii_case(terminator)
+				{
+					self.advance(1);
+				}
+				self.advance(terminator.chars().count());
+			}
+			
+			fn advance_to_end_tag(&mut self, local_name: &str)
+			{
+				let end_tag = format!("</{}", local_name);
+				let end_tag_length = end_tag.chars().count();
+				while !self.is_at_end()
+				{
+					if self.starts_with_ignoring_ascii_case(&end_tag)
+					{
+						match self.peek(end_tag_length)
+						{
+							Some('/') | Some('>') => return,
+							Some(character) if character.is_ascii_whitespace() => return,
+							_ => (),
+						}
+					}
+					self.advance(1);
+				}
+			}
+			
+			fn read_tag_name(&mut self) -> String
+			{
+				let mut tag_name = String::new();
+				while let Some(character) = self.peek(0)
+				{
+					if character.is_ascii_whitespace() || character == '/' || character == '>'
+					{
+						break;
+					}
+					tag_name.push(character.to_ascii_lowercase());
+					self.advance(1);
+				}
+				tag_name
+			}
+			
+			// Returns whether the tag was self-closing.
+			fn advance_past_attributes(&mut self) -> bool
+			{
+				loop
+				{
+					match self.peek(0)
+					{
+						None => return false,
+						
+						Some('>') =>
+						{
+							self.advance(1);
+							return false
+						}
+						
+						Some('/') =>
+						{
+							self.advance(1);
+							if self.peek(0) == Some('>')
+							{
+								self.advance(1);
+								return true
+							}
+						}
+						
+						Some(character) if character.is_ascii_whitespace() => self.advance(1),
+						
+						Some(_) =>
+						{
+							self.advance(1);
+							while let Some(character) = self.peek(0)
+							{
+								if character.is_ascii_whitespace() || character == '/' || character == '>' || character == '='
+								{
+									break;
+								}
+								self.advance(1);
+							}
+							
+							while self.is_at_whitespace()
+							{
+								self.advance(1);
+							}
+							
+							if self.peek(0) == Some('=')
+							{
+								self.advance(1);
+								while self.is_at_whitespace()
+								{
+									self.advance(1);
+								}
+								
+								match self.peek(0)
+								{
+									Some(quote) if quote == '"' || quote == '\'' =>
+									{
+										self.advance(1);
+										while let Some(character) = self.peek(0)
+										{
+											self.advance(1);
+											if character == quote
+											{
+												break;
+											}
+										}
+									}
+									
+									_ => while let Some(character) = self.peek(0)
+									{
+										if character.is_ascii_whitespace() || character == '>'
+										{
+											break;
+										}
+										self.advance(1);
+									},
+								}
+							}
+						}
+					}
+				}
+			}
+		}
+		
+		let mut cursor = Cursor
+		{
+			characters: String::from_utf8_lossy(source).chars().collect(),
+			index: 0,
+			line: 1,
+			column: 1,
+		};
+		if options.discard_bom && cursor.peek(0) == Some('\u{FEFF}')
+		{
+			cursor.index += 1;
+		}
+		
+		let mut starts = Vec::new();
+		let mut foreign_content_depth = 0usize;
+		while !cursor.is_at_end()
+		{
+			if cursor.peek(0) != Some('<')
+			{
+				cursor.advance(1);
+				continue;
+			}
+			
+			let (line, column) = (cursor.line, cursor.column);
+			let mut push = |kind: MarkupStartKind, end_line: u64| starts.push(MarkupStart { kind, line, column, end_line });
+			
+			if cursor.starts_with_ignoring_ascii_case("<!--")
+			{
+				cursor.advance(4);
+				if cursor.peek(0) == Some('>')
+				{
+					cursor.advance(1);
+				}
+				else if cursor.starts_with_ignoring_ascii_case("->")
+				{
+					cursor.advance(2);
+				}
+				else
+				{
+					while !cursor.is_at_end() && !cursor.starts_with_ignoring_ascii_case("-->") && !cursor.starts_with_ignoring_ascii_case("--!>")
+					{
+						cursor.advance(1);
+					}
+					cursor.advance_past(">");
+				}
+				push(Comment, cursor.line);
+			}
+			else if foreign_content_depth > 0 && cursor.starts_with_ignoring_ascii_case("<![CDATA[")
+			{
+				cursor.advance_past("]]>");
+			}
+			else if cursor.starts_with_ignoring_ascii_case("<!DOCTYPE")
+			{
+				cursor.advance_past(">");
+				push(Doctype, cursor.line);
+			}
+			else if cursor.starts_with_ignoring_ascii_case("<!") || cursor.starts_with_ignoring_ascii_case("<?")
+			{
+				cursor.advance_past(">");
+				push(Comment, cursor.line);
+			}
+			else if cursor.starts_with_ignoring_ascii_case("</")
+			{
+				match cursor.peek(2)
+				{
+					Some(character) if character.is_ascii_alphabetic() =>
+					{
+						cursor.advance(2);
+						let local_name = cursor.read_tag_name();
+						cursor.advance_past_attributes();
+						if foreign_content_depth > 0 && (local_name == "svg" || local_name == "math")
+						{
+							foreign_content_depth -= 1;
+						}
+					}
+					
+					Some('>') => cursor.advance(3),
+					
+					_ =>
+					{
+						cursor.advance_past(">");
+						push(Comment, cursor.line);
+					}
+				}
+			}
+			else if cursor.peek(1).map(|character| character.is_ascii_alphabetic()).unwrap_or(false)
+			{
+				cursor.advance(1);
+				let local_name = cursor.read_tag_name();
+				let self_closing = cursor.advance_past_attributes();
+				push(StartTag(local_name.clone()), cursor.line);
+				
+				match &local_name[..]
+				{
+					"svg" | "math" if !self_closing => foreign_content_depth += 1,
+					
+					_ if foreign_content_depth > 0 => (),
+					
+					"iframe" | "noembed" | "noframes" | "script" | "style" | "textarea" | "title" | "xmp" => cursor.advance_to_end_tag(&local_name),
+					
+					"noscript" if options.scripting_enabled => cursor.advance_to_end_tag(&local_name),
+					
+					"plaintext" => break,
+					
+					_ => (),
+				}
+			}
+			else
+			{
+				cursor.advance(1);
+			}
+		}
+		
+		Self
+		{
+			taken: vec![false; starts.len()],
+			starts,
+			next: 0,
+		}
+	}
+	
+	// Where the markup that a node of `kind` was created from starts, if there is any, given the line html5ever's tokenizer was on when the node was created.
+	// Nodes for which there is no markup, eg an implied `<tbody>`, have none.
+	fn take(&mut self, kind: &MarkupStartKind, end_line: u64) -> Option<(u64, u64)>
+	{
+		// Nodes are created in the order of the tokens they are created from, so markup which ended on an earlier line than this will never be taken.
+		while self.next < self.starts.len() && (self.taken[self.next] || self.starts[self.next].end_line < end_line)
+		{
+			self.next += 1;
+		}
+		
+		let mut index = self.next;
+		while index < self.starts.len() && self.starts[index].end_line == end_line
+		{
+			if !self.taken[index] && self.starts[index].kind == *kind
+			{
+				self.taken[index] = true;
+				let start = &self.starts[index];
+				return Some((start.line, start.column));
+			}
+			index += 1;
+		}
+		None
+	}
+}
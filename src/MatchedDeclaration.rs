@@ -0,0 +1,15 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A declaration of a `MatchedRule`.
+#[derive(Debug, Clone)]
+pub struct MatchedDeclaration
+{
+	/// The declaration, eg `color: red !important`.
+	pub declaration: PropertyDeclaration<Importance>,
+	
+	/// Is this declaration overridden by another declaration of the same property, eg one of greater specificity or one that is `!important`?
+	/// Shorthands are not expanded, but a longhand is overridden by a shorthand that wins over it; eg `margin-top: 5px` is overridden by a later `margin: 0`, but `margin: 0` is not overridden by a later `margin-top: 5px`, as it still sets the other margins.
+	pub overridden: bool,
+}
@@ -0,0 +1,20 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A rule that applies to an element; returned by `CascadeResolver::matched_rules()`.
+#[derive(Debug, Clone)]
+pub struct MatchedRule
+{
+	/// Where the rule came from.
+	pub origin: CascadeOrigin,
+	
+	/// The most specific selector of the rule that matched the element, or `None` for a `style` attribute.
+	pub selector: Option<OurSelector>,
+	
+	/// The specificity of `selector`, or, for a `style` attribute, `CascadeResolver::STYLE_ATTRIBUTE_SPECIFICITY`.
+	pub specificity: u32,
+	
+	/// The declarations of the rule, in source order.
+	pub declarations: Vec<MatchedDeclaration>,
+}
@@ -37,10 +37,189 @@ pub trait NodeExt: Sized + Minify
 	#[inline(always)]
 	fn _previous_or_next_sibling(&self, next: bool, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Option<Self>;
 	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _parent_and_index(&self) -> (Option<Self>, usize);
+	
+	/// Returns the ancestors of this node, from its parent up to and including the root, which is ordinarily of type 'Document'.
+	/// As ancestors are always elements (or the root), there is no need to skip inter-element whitespace, comments or processing instructions.
+	#[inline(always)]
+	fn ancestors(&self) -> Ancestors;
+	
+	/// Returns the descendants of this node in pre-order (document order), not including this node, without recursion.
+	#[inline(always)]
+	fn descendants(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Descendants;
+	
+	/// Returns the descendants of this node in post-order (children before their parent), not including this node, without recursion.
+	#[inline(always)]
+	fn descendants_post_order(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> DescendantsPostOrder;
+	
+	/// Returns an `Edge::Open` and an `Edge::Close` for this node and each of its descendants in depth-first order, without recursion, eg to serialize.
+	#[inline(always)]
+	fn traverse(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Traverse;
+	
+	/// Returns the siblings after this node, nearest first.
+	#[inline(always)]
+	fn following_siblings(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> FollowingSiblings;
+	
+	/// Returns the siblings before this node, nearest first.
+	#[inline(always)]
+	fn preceding_siblings(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> PrecedingSiblings;
+	
+	/// Returns the children of this node that are elements.
+	#[inline(always)]
+	fn children_elements(&self) -> ChildrenElements;
+	
+	/// Returns this node, if it is an element that matches `selector`, or otherwise its nearest ancestor that does, like `Element.closest()`.
+	#[inline(always)]
+	fn closest(&self, selector: &OurSelector) -> Option<Self>;
+	
+	/// Returns whether this node is `scope_root` or one of its descendants, and matches `selector` with `:scope` matching `scope_root`.
+	/// Parse selectors that use `:scope` with `parse_scoped_css_selector()`.
+	#[inline(always)]
+	fn matches_within(&self, selector: &OurSelector, scope_root: &Self) -> bool;
+	
 	/// Used for determining siblings.
 	#[inline(always)]
 	fn is_inter_element_whitespace_comment_or_processing_instruction(&self) -> bool;
 	
+	/// Where in the HTML source this node started, if it was parsed with one of the `RcDomExt` methods ending in `_with_source_locations`.
+	#[inline(always)]
+	fn source_location(&self, source_locations: &SourceLocations) -> Option<SourceLocation>;
+	
+	/// Returns the value of the attribute with the qualified name `name` (eg `href` or `xlink:href`), like `Element.getAttribute()`.
+	/// For an element in the HTML namespace, `name` is first converted to ASCII lower case, as the HTML parser does for attribute names.
+	/// Returns None if there is no such attribute or this node is not an element.
+	#[inline(always)]
+	fn get_attribute(&self, name: &str) -> Option<String>;
+	
+	/// Sets the value of the attribute with the qualified name `name`, adding it, without a namespace, if it does not exist, like `Element.setAttribute()`.
+	/// For an element in the HTML namespace, `name` is first converted to ASCII lower case.
+	/// Does nothing if this node is not an element.
+	#[inline(always)]
+	fn set_attribute(&self, name: &str, value: &str);
+	
+	/// Removes the attribute with the qualified name `name`, returning its value, like `Element.removeAttribute()`.
+	/// For an element in the HTML namespace, `name` is first converted to ASCII lower case.
+	#[inline(always)]
+	fn remove_attribute(&self, name: &str) -> Option<String>;
+	
+	/// Is there an attribute with the qualified name `name`, like `Element.hasAttribute()`?
+	/// For an element in the HTML namespace, `name` is first converted to ASCII lower case.
+	#[inline(always)]
+	fn has_attribute(&self, name: &str) -> bool
+	{
+		self.get_attribute(name).is_some()
+	}
+	
+	/// Returns the value of the attribute in `namespace` (`ns!()` for none) with the local name `local_name`, like `Element.getAttributeNS()`.
+	#[inline(always)]
+	fn get_attribute_ns(&self, namespace: &Namespace, local_name: &LocalName) -> Option<String>;
+	
+	/// Sets the value of the attribute with the namespace and local name of `name`, adding it if it does not exist, like `Element.setAttributeNS()`.
+	/// The prefix of an existing attribute is changed to that of `name`.
+	/// Does nothing if this node is not an element.
+	#[inline(always)]
+	fn set_attribute_ns(&self, name: QualName, value: &str);
+	
+	/// Removes the attribute in `namespace` with the local name `local_name`, returning its value, like `Element.removeAttributeNS()`.
+	#[inline(always)]
+	fn remove_attribute_ns(&self, namespace: &Namespace, local_name: &LocalName) -> Option<String>;
+	
+	/// Is there an attribute in `namespace` with the local name `local_name`, like `Element.hasAttributeNS()`?
+	#[inline(always)]
+	fn has_attribute_ns(&self, namespace: &Namespace, local_name: &LocalName) -> bool
+	{
+		self.get_attribute_ns(namespace, local_name).is_some()
+	}
+	
+	/// Returns a copy of the attributes of this node, in source order.
+	/// The iterator is empty if this node is not an element.
+	#[inline(always)]
+	fn attributes(&self) -> ::std::vec::IntoIter<Attribute>;
+	
+	/// A view of the classes of this node, to check for, add, remove, toggle and replace classes, like `Element.classList`.
+	#[inline(always)]
+	fn class_list(&self) -> ClassList;
+	
+	/// The text of this node and its descendants, like `Node.textContent`: for an element (or document), the text of all descendant text nodes, in document order; for a text node, comment or processing instruction, its content.
+	#[inline(always)]
+	fn text_content(&self) -> String;
+	
+	/// An approximation of the text of this node as rendered, like `HTMLElement.innerText`, eg for indexing pages for search.
+	/// Text is separated by a line break between block-level elements, two between paragraphs and a tab between table cells, and `<br>` is a line break.
+	/// Whitespace is collapsed except within elements, such as `<pre>`, that preserve it.
+	/// Elements that are not rendered, such as `<script>`, `<style>`, `<head>`, those with a `hidden` attribute and those with an inline `display: none`, are excluded.
+	/// If this node is itself not rendered, returns `text_content()`.
+	#[inline(always)]
+	fn inner_text(&self) -> String;
+	
+	/// The HTML of the children of this node, like `Element.innerHTML`.
+	/// If `minify` is true, serializes with `UltraMinifyingHtmlSerializer` (as `Minify` does); otherwise, serializes faithfully with html5ever's serializer.
+	#[inline(always)]
+	fn inner_html(&self, minify: bool) -> String;
+	
+	/// The HTML of this node and its children, like `Element.outerHTML`.
+	/// If `minify` is true, serializes with `UltraMinifyingHtmlSerializer` (as `Minify` does); otherwise, serializes faithfully with html5ever's serializer.
+	/// For a document node, this is the same as `inner_html()`.
+	#[inline(always)]
+	fn outer_html(&self, minify: bool) -> String;
+	
+	/// Replaces the children of this element with the nodes parsed from `html`, like setting `Element.innerHTML`.
+	/// `html` is parsed as a fragment in the context of this element (eg `<tr>` is valid if this element is a `<tbody>`).
+	/// Returns the inserted top-level nodes, or `None`, changing nothing, if this node is not an element (eg it is the document node).
+	#[inline(always)]
+	fn set_inner_html(&self, rc_dom: &mut RcDom, html: &str) -> Option<Vec<Rc<Node>>>;
+	
+	/// Replaces this node with the nodes parsed from `html`, like setting `Element.outerHTML`.
+	/// `html` is parsed as a fragment in the context of this node's parent element.
+	/// Returns the inserted top-level nodes, or `None`, changing nothing, if this node has no parent or its parent is not an element (eg this node is the `<html>` element).
+	#[inline(always)]
+	fn replace_with_html(&self, rc_dom: &mut RcDom, html: &str) -> Option<Vec<Rc<Node>>>;
+	
+	/// A copy of this node and its descendants, including attributes, text, comments and template contents, that has no parent.
+	/// Unlike `Rc::clone()`, changes to the copy do not affect this node.
+	#[inline(always)]
+	fn deep_clone(&self) -> Rc<Node>;
+	
+	/// Converts this element and its descendants into an `UnattachedNode`, eg to attach copies of it elsewhere with `attach_child()`.
+	/// Only the local names of elements are kept; comments and processing instructions are discarded, and the contents of a template element become its children.
+	/// Returns `None` if this node is not an element.
+	#[inline(always)]
+	fn to_unattached_node(&self) -> Option<UnattachedNode>;
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _html(&self, minify: bool, traversal_scope: TraversalScope) -> String;
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _parse_fragment_in_context_of(&self, html: &str) -> Option<Vec<Rc<Node>>>;
+	
+	// A copy of this node without its children; a copy of its template contents, if any, is likewise without children, and is added to `pending` along with the template contents.
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _shallow_clone(&self, pending: &mut Vec<(Rc<Node>, Rc<Node>)>) -> Rc<Node>;
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _attribute_position<P: Fn(&Attribute) -> bool>(&self, predicate: P) -> Option<usize>;
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _attribute_name_for_element(&self, name: &str) -> String;
+	
+	/// The accessible name of this node, eg as announced by a screen reader, computed following the W3C [accname](https://www.w3.org/TR/accname-1.2/) algorithm.
+	/// Uses `aria-labelledby`, `aria-label`, `<label>`, `alt`, `title` and, for roles such as `button` and `link`, the text of descendants; hidden elements are excluded.
+	/// Returns an empty string if there is no accessible name.
+	#[inline(always)]
+	fn accessible_name(&self) -> String;
+	
+	/// The accessible description of this node, computed from `aria-describedby` or, if not used for the accessible name, `title`.
+	/// Returns an empty string if there is no accessible description.
+	#[inline(always)]
+	fn accessible_description(&self) -> String;
+	
 	/// Used to attach an unattached child node to a parent
 	#[inline(always)]
 	fn attach_child(&self, rc_dom: &mut RcDom, child: UnattachedNode) -> Rc<Node>;
@@ -218,6 +397,94 @@ impl NodeExt for Rc<Node>
 		self.children.borrow().get(0).map(|child| child.clone())
 	}
 	
+	#[inline(always)]
+	fn _parent_and_index(&self) -> (Option<Self>, usize)
+	{
+		match self.parent()
+		{
+			None => (None, 0),
+			Some(parent) =>
+			{
+				let index = parent.children.borrow().iter().position(|child| Rc::ptr_eq(child, self)).unwrap();
+				(Some(parent), index)
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn ancestors(&self) -> Ancestors
+	{
+		Ancestors::new(self)
+	}
+	
+	#[inline(always)]
+	fn descendants(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Descendants
+	{
+		Descendants::new(self, skip_inter_element_whitespace_comment_or_processing_instructions)
+	}
+	
+	#[inline(always)]
+	fn descendants_post_order(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> DescendantsPostOrder
+	{
+		DescendantsPostOrder::new(self, skip_inter_element_whitespace_comment_or_processing_instructions)
+	}
+	
+	#[inline(always)]
+	fn traverse(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Traverse
+	{
+		Traverse::new(self, skip_inter_element_whitespace_comment_or_processing_instructions)
+	}
+	
+	#[inline(always)]
+	fn following_siblings(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> FollowingSiblings
+	{
+		FollowingSiblings::new(self, skip_inter_element_whitespace_comment_or_processing_instructions)
+	}
+	
+	#[inline(always)]
+	fn preceding_siblings(&self, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> PrecedingSiblings
+	{
+		PrecedingSiblings::new(self, skip_inter_element_whitespace_comment_or_processing_instructions)
+	}
+	
+	#[inline(always)]
+	fn children_elements(&self) -> ChildrenElements
+	{
+		ChildrenElements::new(self)
+	}
+	
+	#[inline(always)]
+	fn closest(&self, selector: &OurSelector) -> Option<Self>
+	{
+		if self.matches(selector)
+		{
+			Some(self.clone())
+		}
+		else
+		{
+			self.ancestors().find(|ancestor| ancestor.matches(selector))
+		}
+	}
+	
+	#[inline(always)]
+	fn matches_within(&self, selector: &OurSelector, scope_root: &Self) -> bool
+	{
+		match self.data
+		{
+			NodeData::Element { .. } => (),
+			
+			_ => return false,
+		}
+		
+		let is_within = Rc::ptr_eq(self, scope_root) || self.ancestors().any(|ancestor| Rc::ptr_eq(&ancestor, scope_root));
+		is_within && matches(selector, &ElementNode
+		{
+			node: self.clone(),
+			dynamic_pseudo_class_policy: DynamicPseudoClassPolicy::default(),
+			scope_root: Some(scope_root.clone()),
+		})
+	}
+	
 	#[doc(hidden)]
 	#[inline(always)]
 	fn _previous_or_next_sibling(&self, next: bool, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Option<Self>
@@ -280,6 +547,392 @@ impl NodeExt for Rc<Node>
 		}
 	}
 	
+	#[inline(always)]
+	fn source_location(&self, source_locations: &SourceLocations) -> Option<SourceLocation>
+	{
+		source_locations.get(self)
+	}
+	
+	#[inline(always)]
+	fn get_attribute(&self, name: &str) -> Option<String>
+	{
+		let name = self._attribute_name_for_element(name);
+		let index = self._attribute_position(|attribute| attribute.has_qualified_name(&name))?;
+		match self.data
+		{
+			NodeData::Element { ref attrs, .. } => Some(attrs.borrow()[index].value.to_string()),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn set_attribute(&self, name: &str, value: &str)
+	{
+		let name = self._attribute_name_for_element(name);
+		let index = self._attribute_position(|attribute| attribute.has_qualified_name(&name));
+		if let NodeData::Element { ref attrs, .. } = self.data
+		{
+			let mut attributes = attrs.borrow_mut();
+			match index
+			{
+				Some(index) => attributes[index].value = StrTendril::from_slice(value),
+				None => attributes.push(Attribute::local(LocalName::from(name), value)),
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn remove_attribute(&self, name: &str) -> Option<String>
+	{
+		let name = self._attribute_name_for_element(name);
+		let index = self._attribute_position(|attribute| attribute.has_qualified_name(&name))?;
+		match self.data
+		{
+			NodeData::Element { ref attrs, .. } => Some(attrs.borrow_mut().remove(index).value.to_string()),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn get_attribute_ns(&self, namespace: &Namespace, local_name: &LocalName) -> Option<String>
+	{
+		let index = self._attribute_position(|attribute| &attribute.name.ns == namespace && &attribute.name.local == local_name)?;
+		match self.data
+		{
+			NodeData::Element { ref attrs, .. } => Some(attrs.borrow()[index].value.to_string()),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn set_attribute_ns(&self, name: QualName, value: &str)
+	{
+		let index = self._attribute_position(|attribute| attribute.name.ns == name.ns && attribute.name.local == name.local);
+		if let NodeData::Element { ref attrs, .. } = self.data
+		{
+			let mut attributes = attrs.borrow_mut();
+			let attribute = Attribute
+			{
+				name,
+				value: StrTendril::from_slice(value),
+			};
+			match index
+			{
+				Some(index) => attributes[index] = attribute,
+				None => attributes.push(attribute),
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn remove_attribute_ns(&self, namespace: &Namespace, local_name: &LocalName) -> Option<String>
+	{
+		let index = self._attribute_position(|attribute| &attribute.name.ns == namespace && &attribute.name.local == local_name)?;
+		match self.data
+		{
+			NodeData::Element { ref attrs, .. } => Some(attrs.borrow_mut().remove(index).value.to_string()),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn attributes(&self) -> ::std::vec::IntoIter<Attribute>
+	{
+		match self.data
+		{
+			NodeData::Element { ref attrs, .. } => attrs.borrow().clone().into_iter(),
+			_ => Vec::new().into_iter(),
+		}
+	}
+	
+	#[inline(always)]
+	fn class_list(&self) -> ClassList
+	{
+		ClassList::new(self)
+	}
+	
+	#[inline(always)]
+	fn text_content(&self) -> String
+	{
+		match self.data
+		{
+			Text { ref contents } => contents.borrow().to_string(),
+			
+			Comment { ref contents } => contents.to_string(),
+			
+			ProcessingInstruction { ref contents, .. } => contents.to_string(),
+			
+			_ =>
+			{
+				let mut text_content = String::new();
+				for descendant in self.descendants(false)
+				{
+					if let Text { ref contents } = descendant.data
+					{
+						text_content.push_str(&contents.borrow());
+					}
+				}
+				text_content
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn inner_text(&self) -> String
+	{
+		InnerText::of(self)
+	}
+	
+	#[inline(always)]
+	fn inner_html(&self, minify: bool) -> String
+	{
+		let context = match self.data
+		{
+			NodeData::Element { ref name, .. } => Some(name.clone()),
+			_ => None,
+		};
+		self._html(minify, TraversalScope::ChildrenOnly(context))
+	}
+	
+	#[inline(always)]
+	fn outer_html(&self, minify: bool) -> String
+	{
+		match self.data
+		{
+			Document => self.inner_html(minify),
+			_ => self._html(minify, TraversalScope::IncludeNode),
+		}
+	}
+	
+	#[inline(always)]
+	fn set_inner_html(&self, rc_dom: &mut RcDom, html: &str) -> Option<Vec<Rc<Node>>>
+	{
+		let nodes = self._parse_fragment_in_context_of(html)?;
+		
+		let children = self.children.borrow().clone();
+		for child in children.iter()
+		{
+			rc_dom.remove_from_parent(child);
+		}
+		
+		for node in nodes.iter()
+		{
+			rc_dom.append(self, AppendNode(node.clone()));
+		}
+		Some(nodes)
+	}
+	
+	#[inline(always)]
+	fn replace_with_html(&self, rc_dom: &mut RcDom, html: &str) -> Option<Vec<Rc<Node>>>
+	{
+		let nodes = self.parent()?._parse_fragment_in_context_of(html)?;
+		
+		for node in nodes.iter()
+		{
+			rc_dom.append_before_sibling(self, AppendNode(node.clone()));
+		}
+		rc_dom.remove_from_parent(self);
+		Some(nodes)
+	}
+	
+	#[inline(always)]
+	fn deep_clone(&self) -> Rc<Node>
+	{
+		// Nodes, and their clones, whose descendants have still to be cloned; template contents are added as they are found, as `traverse()` does not visit them.
+		let mut pending = Vec::new();
+		let clone = self._shallow_clone(&mut pending);
+		pending.push((self.clone(), clone.clone()));
+		
+		while let Some((root, root_clone)) = pending.pop()
+		{
+			let mut open_clones: Vec<Rc<Node>> = Vec::new();
+			for edge in root.traverse(false)
+			{
+				match edge
+				{
+					Edge::Open(ref node) if Rc::ptr_eq(node, &root) => open_clones.push(root_clone.clone()),
+					
+					Edge::Open(node) =>
+					{
+						let node_clone = node._shallow_clone(&mut pending);
+						{
+							let parent_clone = open_clones.last().unwrap();
+							node_clone.parent.set(Some(Rc::downgrade(parent_clone)));
+							parent_clone.children.borrow_mut().push(node_clone.clone());
+						}
+						open_clones.push(node_clone);
+					}
+					
+					Edge::Close(_) =>
+					{
+						open_clones.pop();
+					}
+				}
+			}
+		}
+		
+		clone
+	}
+	
+	#[inline(always)]
+	fn to_unattached_node(&self) -> Option<UnattachedNode>
+	{
+		match self.data
+		{
+			NodeData::Element { ref name, ref attrs, ref template_contents, .. } =>
+			{
+				let children_of = template_contents.as_ref().unwrap_or(self);
+				
+				let mut children = Vec::with_capacity(children_of.children.borrow().len());
+				for child in children_of.children.borrow().iter()
+				{
+					match child.data
+					{
+						Text { ref contents } => children.push(Left(contents.borrow().to_string())),
+						
+						NodeData::Element { .. } => children.push(Right(child.to_unattached_node().unwrap())),
+						
+						_ => (),
+					}
+				}
+				
+				Some
+				(
+					UnattachedNode
+					{
+						local_name: name.local.clone(),
+						attributes: attrs.borrow().clone(),
+						children,
+					}
+				)
+			}
+			
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn _html(&self, minify: bool, traversal_scope: TraversalScope) -> String
+	{
+		if minify
+		{
+			match traversal_scope
+			{
+				TraversalScope::IncludeNode => self.minify_to_string(false),
+				TraversalScope::ChildrenOnly(_) => self.children.minify_to_string(false),
+			}
+		}
+		else
+		{
+			let mut bytes = Vec::new();
+			let serialize_options = SerializeOpts
+			{
+				traversal_scope,
+				.. SerializeOpts::default()
+			};
+			serialize(&mut bytes, self, serialize_options).expect("Writing to a Vec<u8> should never fail");
+			String::from_utf8(bytes).expect("html5ever should only serialize valid UTF-8")
+		}
+	}
+	
+	#[inline(always)]
+	fn _parse_fragment_in_context_of(&self, html: &str) -> Option<Vec<Rc<Node>>>
+	{
+		let context = match self.data
+		{
+			NodeData::Element { ref name, .. } => name.clone(),
+			_ => return None,
+		};
+		Some(RcDom::from_fragment_str(html, context).detach_document_node_children())
+	}
+	
+	#[inline(always)]
+	fn _shallow_clone(&self, pending: &mut Vec<(Rc<Node>, Rc<Node>)>) -> Rc<Node>
+	{
+		let data = match self.data
+		{
+			Document => Document,
+			
+			Doctype { ref name, ref public_id, ref system_id } => Doctype
+			{
+				name: name.clone(),
+				public_id: public_id.clone(),
+				system_id: system_id.clone(),
+			},
+			
+			Text { ref contents } => Text
+			{
+				contents: RefCell::new(contents.borrow().clone()),
+			},
+			
+			Comment { ref contents } => Comment
+			{
+				contents: contents.clone(),
+			},
+			
+			NodeData::Element { ref name, ref attrs, ref template_contents, mathml_annotation_xml_integration_point } => NodeData::Element
+			{
+				name: name.clone(),
+				attrs: RefCell::new(attrs.borrow().clone()),
+				template_contents: template_contents.as_ref().map(|template_contents|
+				{
+					let template_contents_clone = template_contents._shallow_clone(pending);
+					pending.push((template_contents.clone(), template_contents_clone.clone()));
+					template_contents_clone
+				}),
+				mathml_annotation_xml_integration_point,
+			},
+			
+			ProcessingInstruction { ref target, ref contents } => ProcessingInstruction
+			{
+				target: target.clone(),
+				contents: contents.clone(),
+			},
+		};
+		
+		Rc::new
+		(
+			Node
+			{
+				parent: Cell::new(None),
+				children: RefCell::new(Vec::with_capacity(self.children.borrow().len())),
+				data,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn _attribute_position<P: Fn(&Attribute) -> bool>(&self, predicate: P) -> Option<usize>
+	{
+		match self.data
+		{
+			NodeData::Element { ref attrs, .. } => attrs.borrow().iter().position(predicate),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn _attribute_name_for_element(&self, name: &str) -> String
+	{
+		match self.data
+		{
+			NodeData::Element { name: ref element_name, .. } if element_name.ns == ns!(html) => name.to_ascii_lowercase(),
+			_ => name.to_owned(),
+		}
+	}
+	
+	#[inline(always)]
+	fn accessible_name(&self) -> String
+	{
+		AccessibleNameComputation::name(self)
+	}
+	
+	#[inline(always)]
+	fn accessible_description(&self) -> String
+	{
+		AccessibleNameComputation::description(self)
+	}
+	
 	#[inline(always)]
 	fn attach_child(&self, rc_dom: &mut RcDom, child: UnattachedNode) -> Rc<Node>
 	{
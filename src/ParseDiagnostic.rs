@@ -0,0 +1,97 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A problem found whilst parsing HTML, with the position it was found at if known.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ParseDiagnostic
+{
+	/// One-based line number at which the problem was found.
+	/// This is the line the tokenizer was on when the problem was reported, which, for a tag spanning several lines, is the line containing its end.
+	/// `None` if the position is not known, eg if the diagnostic was made from the `errors` of an already parsed `RcDom`.
+	pub line: Option<u64>,
+	
+	/// One-based column number at which the problem was found.
+	/// html5ever does not currently report columns to a `TreeSink`, so this is `None` for diagnostics produced whilst parsing.
+	pub column: Option<u64>,
+	
+	/// Description of the problem, as reported by html5ever.
+	pub message: Cow<'static, str>,
+	
+	/// How serious the problem is.
+	pub severity: ParseDiagnosticSeverity,
+}
+
+impl Display for ParseDiagnostic
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		match (self.line, self.column)
+		{
+			(Some(line), Some(column)) => write!(f, "{}:{}: ", line, column)?,
+			(Some(line), None) => write!(f, "{}: ", line)?,
+			_ => (),
+		}
+		write!(f, "{}: {}", self.severity, self.message)
+	}
+}
+
+impl ParseDiagnostic
+{
+	/// Creates a new error diagnostic.
+	#[inline(always)]
+	pub fn error(line: Option<u64>, message: Cow<'static, str>) -> Self
+	{
+		Self
+		{
+			line,
+			column: None,
+			message,
+			severity: ParseDiagnosticSeverity::Error,
+		}
+	}
+	
+	/// Creates a new warning diagnostic.
+	#[inline(always)]
+	pub fn warning(line: Option<u64>, message: Cow<'static, str>) -> Self
+	{
+		Self
+		{
+			line,
+			column: None,
+			message,
+			severity: ParseDiagnosticSeverity::Warning,
+		}
+	}
+	
+	/// Is this diagnostic an error?
+	#[inline(always)]
+	pub fn is_error(&self) -> bool
+	{
+		self.severity == ParseDiagnosticSeverity::Error
+	}
+	
+	/// Creates a compiler-style report, one line per diagnostic, eg `path/to/document.html:12: error: Unexpected token`.
+	pub fn compiler_style_report(path: &Path, diagnostics: &[ParseDiagnostic]) -> String
+	{
+		let mut report = String::new();
+		for diagnostic in diagnostics.iter()
+		{
+			if !report.is_empty()
+			{
+				report.push('\n');
+			}
+			let separator = if diagnostic.line.is_some()
+			{
+				":"
+			}
+			else
+			{
+				": "
+			};
+			report.push_str(&format!("{}{}{}", path.display(), separator, diagnostic));
+		}
+		report
+	}
+}
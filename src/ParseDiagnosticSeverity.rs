@@ -0,0 +1,38 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// How serious a parse diagnostic is.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ParseDiagnosticSeverity
+{
+	/// A parse error as defined by the HTML 5 specification; the document is not conforming.
+	Error,
+	
+	/// Something that is not a parse error but that is probably unwanted, eg the document was parsed in quirks mode.
+	Warning,
+}
+
+impl Display for ParseDiagnosticSeverity
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.to_str())
+	}
+}
+
+impl ParseDiagnosticSeverity
+{
+	#[inline(always)]
+	fn to_str(&self) -> &'static str
+	{
+		use self::ParseDiagnosticSeverity::*;
+		
+		match *self
+		{
+			Error => "error",
+			Warning => "warning",
+		}
+	}
+}
@@ -0,0 +1,182 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A tree sink that wraps a `RcDom` and records every parse error, with the line it occurred on, as a `ParseDiagnostic`.
+/// Parsing with this sink produces both the `RcDom` and the diagnostics.
+/// Use the `RcDomExt` methods ending in `_with_diagnostics` rather than using this directly.
+pub struct ParseDiagnosticsTreeSink
+{
+	rc_dom: RcDom,
+	current_line: u64,
+	diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl Default for ParseDiagnosticsTreeSink
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			rc_dom: RcDom::default(),
+			current_line: 1,
+			diagnostics: Vec::new(),
+		}
+	}
+}
+
+impl TreeSink for ParseDiagnosticsTreeSink
+{
+	type Handle = Rc<Node>;
+	
+	type Output = (RcDom, Vec<ParseDiagnostic>);
+	
+	#[inline(always)]
+	fn finish(self) -> Self::Output
+	{
+		(self.rc_dom, self.diagnostics)
+	}
+	
+	#[inline(always)]
+	fn parse_error(&mut self, msg: Cow<'static, str>)
+	{
+		self.diagnostics.push(ParseDiagnostic::error(Some(self.current_line), msg.clone()));
+		self.rc_dom.parse_error(msg)
+	}
+	
+	#[inline(always)]
+	fn get_document(&mut self) -> Self::Handle
+	{
+		self.rc_dom.get_document()
+	}
+	
+	#[inline(always)]
+	fn elem_name<'a>(&'a self, target: &'a Self::Handle) -> ExpandedName<'a>
+	{
+		self.rc_dom.elem_name(target)
+	}
+	
+	#[inline(always)]
+	fn create_element(&mut self, name: QualName, attrs: Vec<Attribute>, flags: ElementFlags) -> Self::Handle
+	{
+		self.rc_dom.create_element(name, attrs, flags)
+	}
+	
+	#[inline(always)]
+	fn create_comment(&mut self, text: StrTendril) -> Self::Handle
+	{
+		self.rc_dom.create_comment(text)
+	}
+	
+	#[inline(always)]
+	fn create_pi(&mut self, target: StrTendril, data: StrTendril) -> Self::Handle
+	{
+		self.rc_dom.create_pi(target, data)
+	}
+	
+	#[inline(always)]
+	fn append(&mut self, parent: &Self::Handle, child: NodeOrText<Self::Handle>)
+	{
+		self.rc_dom.append(parent, child)
+	}
+	
+	#[inline(always)]
+	fn append_based_on_parent_node(&mut self, element: &Self::Handle, prev_element: &Self::Handle, child: NodeOrText<Self::Handle>)
+	{
+		self.rc_dom.append_based_on_parent_node(element, prev_element, child)
+	}
+	
+	#[inline(always)]
+	fn append_doctype_to_document(&mut self, name: StrTendril, public_id: StrTendril, system_id: StrTendril)
+	{
+		self.rc_dom.append_doctype_to_document(name, public_id, system_id)
+	}
+	
+	#[inline(always)]
+	fn mark_script_already_started(&mut self, node: &Self::Handle)
+	{
+		self.rc_dom.mark_script_already_started(node)
+	}
+	
+	#[inline(always)]
+	fn pop(&mut self, node: &Self::Handle)
+	{
+		self.rc_dom.pop(node)
+	}
+	
+	#[inline(always)]
+	fn get_template_contents(&mut self, target: &Self::Handle) -> Self::Handle
+	{
+		self.rc_dom.get_template_contents(target)
+	}
+	
+	#[inline(always)]
+	fn same_node(&self, x: &Self::Handle, y: &Self::Handle) -> bool
+	{
+		self.rc_dom.same_node(x, y)
+	}
+	
+	#[inline(always)]
+	fn set_quirks_mode(&mut self, mode: QuirksMode)
+	{
+		match mode
+		{
+			QuirksMode::Quirks => self.diagnostics.push(ParseDiagnostic::warning(Some(self.current_line), Cow::Borrowed("Document is in quirks mode"))),
+			
+			QuirksMode::LimitedQuirks => self.diagnostics.push(ParseDiagnostic::warning(Some(self.current_line), Cow::Borrowed("Document is in limited quirks mode"))),
+			
+			QuirksMode::NoQuirks => (),
+		}
+		self.rc_dom.set_quirks_mode(mode)
+	}
+	
+	#[inline(always)]
+	fn append_before_sibling(&mut self, sibling: &Self::Handle, new_node: NodeOrText<Self::Handle>)
+	{
+		self.rc_dom.append_before_sibling(sibling, new_node)
+	}
+	
+	#[inline(always)]
+	fn add_attrs_if_missing(&mut self, target: &Self::Handle, attrs: Vec<Attribute>)
+	{
+		self.rc_dom.add_attrs_if_missing(target, attrs)
+	}
+	
+	#[inline(always)]
+	fn associate_with_form(&mut self, target: &Self::Handle, form: &Self::Handle, nodes: (&Self::Handle, Option<&Self::Handle>))
+	{
+		self.rc_dom.associate_with_form(target, form, nodes)
+	}
+	
+	#[inline(always)]
+	fn remove_from_parent(&mut self, target: &Self::Handle)
+	{
+		self.rc_dom.remove_from_parent(target)
+	}
+	
+	#[inline(always)]
+	fn reparent_children(&mut self, node: &Self::Handle, new_parent: &Self::Handle)
+	{
+		self.rc_dom.reparent_children(node, new_parent)
+	}
+	
+	#[inline(always)]
+	fn is_mathml_annotation_xml_integration_point(&self, handle: &Self::Handle) -> bool
+	{
+		self.rc_dom.is_mathml_annotation_xml_integration_point(handle)
+	}
+	
+	#[inline(always)]
+	fn set_current_line(&mut self, line_number: u64)
+	{
+		self.current_line = line_number;
+		self.rc_dom.set_current_line(line_number)
+	}
+	
+	#[inline(always)]
+	fn complete_script(&mut self, node: &Self::Handle) -> NextParserState
+	{
+		self.rc_dom.complete_script(node)
+	}
+}
@@ -0,0 +1,56 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An iterator over the siblings before a node, nearest first; returned by `NodeExt::preceding_siblings()`.
+#[derive(Clone)]
+pub struct PrecedingSiblings
+{
+	parent: Option<Rc<Node>>,
+	remaining: usize,
+	skip_inter_element_whitespace_comment_or_processing_instructions: bool,
+}
+
+impl Debug for PrecedingSiblings
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "PrecedingSiblings {{ remaining: {}, skip_inter_element_whitespace_comment_or_processing_instructions: {} }}", self.remaining, self.skip_inter_element_whitespace_comment_or_processing_instructions)
+	}
+}
+
+impl Iterator for PrecedingSiblings
+{
+	type Item = Rc<Node>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		while self.remaining != 0
+		{
+			self.remaining -= 1;
+			let sibling = self.parent.as_ref()?.children.borrow().get(self.remaining).cloned()?;
+			if !(self.skip_inter_element_whitespace_comment_or_processing_instructions && sibling.is_inter_element_whitespace_comment_or_processing_instruction())
+			{
+				return Some(sibling)
+			}
+		}
+		None
+	}
+}
+
+impl PrecedingSiblings
+{
+	#[inline(always)]
+	fn new(node: &Rc<Node>, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Self
+	{
+		let (parent, index) = node._parent_and_index();
+		Self
+		{
+			parent,
+			remaining: index,
+			skip_inter_element_whitespace_comment_or_processing_instructions,
+		}
+	}
+}
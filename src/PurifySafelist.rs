@@ -0,0 +1,95 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Selectors that `purify_stylesheet()` always keeps, even when they match no element, eg because the elements or classes they match are only added by JavaScript.
+/// The default keeps nothing.
+/// Use the `with_` methods to add to it, eg `PurifySafelist::default().with_pattern("is-*")`.
+#[derive(Debug, Default, Clone)]
+pub struct PurifySafelist
+{
+	selectors: Vec<OurSelector>,
+	patterns: Vec<String>,
+}
+
+impl PurifySafelist
+{
+	/// Always keeps `selector`.
+	#[inline(always)]
+	pub fn with_selector(mut self, selector: OurSelector) -> Self
+	{
+		self.selectors.push(selector);
+		self
+	}
+	
+	/// Always keeps selectors that use an id, class or element name that matches `pattern`, in which `*` matches any run of characters, eg `js-*` or `*-active`.
+	#[inline(always)]
+	pub fn with_pattern(mut self, pattern: &str) -> Self
+	{
+		self.patterns.push(pattern.to_owned());
+		self
+	}
+	
+	#[inline(always)]
+	fn is_safelisted(&self, selector: &OurSelector) -> bool
+	{
+		self.selectors.contains(selector) || (!self.patterns.is_empty() && self.any_name_matches_a_pattern(selector.iter_raw_match_order()))
+	}
+	
+	fn any_name_matches_a_pattern<'a, I: Iterator<Item=&'a Component<OurSelectorImpl>>>(&self, components: I) -> bool
+	{
+		for component in components
+		{
+			let matches = match *component
+			{
+				Component::ID(ref atom) | Component::Class(ref atom) => self.matches_a_pattern(atom),
+				Component::LocalName(ref name) => self.matches_a_pattern(&name.name),
+				Component::Negation(ref components) => self.any_name_matches_a_pattern(components.iter()),
+				_ => false,
+			};
+			
+			if matches
+			{
+				return true;
+			}
+		}
+		false
+	}
+	
+	#[inline(always)]
+	fn matches_a_pattern(&self, name: &str) -> bool
+	{
+		self.patterns.iter().any(|pattern| Self::matches_pattern(pattern, name))
+	}
+	
+	fn matches_pattern(pattern: &str, name: &str) -> bool
+	{
+		let mut parts = pattern.split('*');
+		let first = parts.next().unwrap();
+		if !name.starts_with(first)
+		{
+			return false;
+		}
+		
+		let mut remainder = &name[first.len() ..];
+		let mut previous: Option<&str> = None;
+		for part in parts
+		{
+			if let Some(previous) = previous
+			{
+				match remainder.find(previous)
+				{
+					None => return false,
+					Some(index) => remainder = &remainder[index + previous.len() ..],
+				}
+			}
+			previous = Some(part);
+		}
+		
+		match previous
+		{
+			None => remainder.is_empty(),
+			Some(last) => remainder.ends_with(last),
+		}
+	}
+}
@@ -9,25 +9,193 @@ pub trait RcDomExt: Sized + Minify
 	#[inline(always)]
 	fn from_file_path_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<P: AsRef<Path>>(html_document_file_path: P) -> Result<Self, HtmlError>;
 	
+	/// Creates an instance of an HTML DOM from a file path which is verified according to `policy`, stripped and with a sane DocType.
+	/// Returns the warnings for checks that `policy` said to warn about alongside the DOM.
+	/// Whatever `policy` says, fails if the HTML is a fragment rather than a document or if stripping fails (see `recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type()`).
+	#[inline(always)]
+	fn from_file_path_verified_with_policy_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<P: AsRef<Path>>(html_document_file_path: P, policy: &VerificationPolicy) -> Result<(Self, VerificationReport), HtmlError>;
+	
 	/// Creates an instance of an HTML DOM from a file path
 	#[inline(always)]
 	fn from_file_path<P: AsRef<Path>>(file_path: P) -> Result<Self, HtmlError>;
 	
+	/// Creates an instance of an HTML DOM from a file path using the given parse options.
+	#[inline(always)]
+	fn from_file_path_with_options<P: AsRef<Path>>(file_path: P, options: &HtmlParseOptions) -> Result<Self, HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a file path, along with a diagnostic (including line number) for every parse error.
+	#[inline(always)]
+	fn from_file_path_with_diagnostics<P: AsRef<Path>>(file_path: P) -> Result<(Self, Vec<ParseDiagnostic>), HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a file path using the given parse options, along with a diagnostic (including line number) for every parse error.
+	#[inline(always)]
+	fn from_file_path_with_diagnostics_and_options<P: AsRef<Path>>(file_path: P, options: &HtmlParseOptions) -> Result<(Self, Vec<ParseDiagnostic>), HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a reader (eg standard in or a decompressing stream) which is verified, stripped and with a sane DocType.
+	/// The reader is parsed incrementally; `context` is used to report errors.
+	#[inline(always)]
+	fn from_reader_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<R: Read, P: AsRef<Path>>(reader: R, context: P) -> Result<Self, HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a reader (eg standard in or a decompressing stream) using the given parse options, which is verified, stripped and with a sane DocType.
+	/// The reader is parsed incrementally; `context` is used to report errors.
+	#[inline(always)]
+	fn from_reader_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type_and_options<R: Read, P: AsRef<Path>>(reader: R, context: P, options: &HtmlParseOptions) -> Result<Self, HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a reader (eg standard in or a decompressing stream).
+	/// The reader is parsed incrementally; `context` is used to report errors.
+	#[inline(always)]
+	fn from_reader<R: Read, P: AsRef<Path>>(reader: R, context: P) -> Result<Self, HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a reader (eg standard in or a decompressing stream) using the given parse options.
+	/// The reader is parsed incrementally; `context` is used to report errors.
+	#[inline(always)]
+	fn from_reader_with_options<R: Read, P: AsRef<Path>>(reader: R, context: P, options: &HtmlParseOptions) -> Result<Self, HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a file path, along with where in the source every node (other than the document node) started.
+	/// Use `NodeExt.source_location()` to find a node's location.
+	#[inline(always)]
+	fn from_file_path_with_source_locations<P: AsRef<Path>>(file_path: P) -> Result<(Self, SourceLocations), HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a file path using the given parse options, along with where in the source every node (other than the document node) started.
+	/// Use `NodeExt.source_location()` to find a node's location.
+	#[inline(always)]
+	fn from_file_path_with_source_locations_and_options<P: AsRef<Path>>(file_path: P, options: &HtmlParseOptions) -> Result<(Self, SourceLocations), HtmlError>;
+	
 	/// Creates an instance of an HTML DOM from bytes which is verified, stripped and with a sane DocType.
 	#[inline(always)]
 	fn from_bytes_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<P: AsRef<Path>>(bytes: &[u8], context: P) -> Result<Self, HtmlError>;
 	
+	/// Creates an instance of an HTML DOM from bytes which is verified according to `policy`, stripped and with a sane DocType.
+	/// Returns the warnings for checks that `policy` said to warn about alongside the DOM.
+	/// Whatever `policy` says, fails if the HTML is a fragment rather than a document or if stripping fails (see `recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type()`).
+	#[inline(always)]
+	fn from_bytes_verified_with_policy_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<P: AsRef<Path>>(bytes: &[u8], context: P, policy: &VerificationPolicy) -> Result<(Self, VerificationReport), HtmlError>;
+	
 	/// Creates an instance of an HTML DOM from bytes
 	#[inline(always)]
 	fn from_bytes(bytes: &[u8]) -> Self;
 	
+	/// Creates an instance of an HTML DOM from bytes using the given parse options.
+	#[inline(always)]
+	fn from_bytes_with_options(bytes: &[u8], options: &HtmlParseOptions) -> Self;
+	
+	/// Creates an instance of an HTML DOM from bytes, along with a diagnostic (including line number) for every parse error.
+	#[inline(always)]
+	fn from_bytes_with_diagnostics(bytes: &[u8]) -> (Self, Vec<ParseDiagnostic>);
+	
+	/// Creates an instance of an HTML DOM from bytes using the given parse options, along with a diagnostic (including line number) for every parse error.
+	#[inline(always)]
+	fn from_bytes_with_diagnostics_and_options(bytes: &[u8], options: &HtmlParseOptions) -> (Self, Vec<ParseDiagnostic>);
+	
+	/// Creates an instance of an HTML DOM from bytes, along with where in the source every node (other than the document node) started.
+	/// Use `NodeExt.source_location()` to find a node's location.
+	#[inline(always)]
+	fn from_bytes_with_source_locations(bytes: &[u8]) -> (Self, SourceLocations);
+	
+	/// Creates an instance of an HTML DOM from bytes using the given parse options, along with where in the source every node (other than the document node) started.
+	/// Use `NodeExt.source_location()` to find a node's location.
+	#[inline(always)]
+	fn from_bytes_with_source_locations_and_options(bytes: &[u8], options: &HtmlParseOptions) -> (Self, SourceLocations);
+	
+	/// Creates an instance of an HTML DOM from a file path whose bytes are in an unknown character encoding, which is sniffed as a browser would (byte order mark, then `transport_layer_charset`, then `<meta charset>`, then `windows-1252`).
+	/// Returns the encoding used alongside the DOM.
+	#[inline(always)]
+	fn from_file_path_sniffing_character_encoding<P: AsRef<Path>>(file_path: P, transport_layer_charset: Option<&str>) -> Result<(Self, SniffedCharacterEncoding), HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a file path whose bytes are in an unknown character encoding, which is sniffed as a browser would, using the given parse options.
+	/// Returns the encoding used alongside the DOM.
+	#[inline(always)]
+	fn from_file_path_sniffing_character_encoding_with_options<P: AsRef<Path>>(file_path: P, transport_layer_charset: Option<&str>, options: &HtmlParseOptions) -> Result<(Self, SniffedCharacterEncoding), HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from bytes in an unknown character encoding, which is sniffed as a browser would (byte order mark, then `transport_layer_charset`, then `<meta charset>`, then `windows-1252`).
+	/// Returns the encoding used alongside the DOM; malformed byte sequences are replaced and recorded in `errors`.
+	#[inline(always)]
+	fn from_bytes_sniffing_character_encoding(bytes: &[u8], transport_layer_charset: Option<&str>) -> (Self, SniffedCharacterEncoding);
+	
+	/// Creates an instance of an HTML DOM from bytes in an unknown character encoding, which is sniffed as a browser would, using the given parse options.
+	/// Returns the encoding used alongside the DOM; malformed byte sequences are replaced and recorded in `errors`.
+	#[inline(always)]
+	fn from_bytes_sniffing_character_encoding_with_options(bytes: &[u8], transport_layer_charset: Option<&str>, options: &HtmlParseOptions) -> (Self, SniffedCharacterEncoding);
+	
+	/// Creates an instance of an HTML DOM from a file path containing a HTML fragment, parsed as a browser would parse the content of an element called `context` (eg `ul` or `tbody`).
+	/// The top-level nodes of the fragment become the children of the document node.
+	#[inline(always)]
+	fn from_fragment_file_path<P: AsRef<Path>>(file_path: P, context: QualName) -> Result<Self, HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from a file path containing a HTML fragment using the given parse options, parsed as a browser would parse the content of an element called `context` (eg `ul` or `tbody`).
+	/// The top-level nodes of the fragment become the children of the document node.
+	#[inline(always)]
+	fn from_fragment_file_path_with_options<P: AsRef<Path>>(file_path: P, context: QualName, options: &HtmlParseOptions) -> Result<Self, HtmlError>;
+	
+	/// Creates an instance of an HTML DOM from bytes containing a HTML fragment, parsed as a browser would parse the content of an element called `context` (eg `ul` or `tbody`).
+	/// The top-level nodes of the fragment become the children of the document node.
+	#[inline(always)]
+	fn from_fragment_bytes(bytes: &[u8], context: QualName) -> Self;
+	
+	/// Creates an instance of an HTML DOM from bytes containing a HTML fragment using the given parse options, parsed as a browser would parse the content of an element called `context` (eg `ul` or `tbody`).
+	/// The top-level nodes of the fragment become the children of the document node.
+	#[inline(always)]
+	fn from_fragment_bytes_with_options(bytes: &[u8], context: QualName, options: &HtmlParseOptions) -> Self;
+	
+	/// Creates an instance of an HTML DOM from a string containing a HTML fragment, parsed as a browser would parse the content of an element called `context` (eg `ul` or `tbody`).
+	/// The top-level nodes of the fragment become the children of the document node.
+	#[inline(always)]
+	fn from_fragment_str(fragment: &str, context: QualName) -> Self
+	{
+		Self::from_fragment_bytes(fragment.as_bytes(), context)
+	}
+	
+	/// Creates an instance of an HTML DOM from a string containing a HTML fragment using the given parse options, parsed as a browser would parse the content of an element called `context` (eg `ul` or `tbody`).
+	/// The top-level nodes of the fragment become the children of the document node.
+	#[inline(always)]
+	fn from_fragment_str_with_options(fragment: &str, context: QualName, options: &HtmlParseOptions) -> Self
+	{
+		Self::from_fragment_bytes_with_options(fragment.as_bytes(), context, options)
+	}
+	
+	/// Removes the children of the document node and returns them without a parent.
+	/// Useful to obtain the top-level nodes of a fragment so that they can be attached elsewhere.
+	#[inline(always)]
+	fn detach_document_node_children(&mut self) -> Vec<Rc<Node>>;
+	
 	/// Verify that this HTML DOM is valid.
+	/// Parse errors are reported without line numbers; use `verify_with_diagnostics()` if they were collected during parsing.
 	#[inline(always)]
 	fn verify(&self, context: &Path) -> Result<(), HtmlError>;
 	
+	/// Verify that this HTML DOM is valid, using the diagnostics collected during parsing (eg by `from_bytes_with_diagnostics()`) to report parse errors.
+	#[inline(always)]
+	fn verify_with_diagnostics(&self, context: &Path, diagnostics: &[ParseDiagnostic]) -> Result<(), HtmlError>;
+	
+	/// Verify this HTML DOM, failing, warning about or ignoring each check according to `policy`.
+	/// Parse errors are reported without line numbers; use `verify_with_diagnostics_and_policy()` if they were collected during parsing.
+	/// A DOM that is a fragment rather than a document always fails.
+	#[inline(always)]
+	fn verify_with_policy(&self, context: &Path, policy: &VerificationPolicy) -> Result<VerificationReport, HtmlError>;
+	
+	/// Verify this HTML DOM, failing, warning about or ignoring each check according to `policy`, using the diagnostics collected during parsing to report parse errors.
+	/// A DOM that is a fragment rather than a document always fails.
+	#[inline(always)]
+	fn verify_with_diagnostics_and_policy(&self, context: &Path, diagnostics: &[ParseDiagnostic], policy: &VerificationPolicy) -> Result<VerificationReport, HtmlError>;
+	
 	/// Remove all comments and processing instructions and make the DOCTYPE a simple 'html' (for HTML 5).
+	/// Fails if a node is not a valid child of its parent, ie a Document node that is a child, a DOCTYPE that is not a child of the Document node or a text node that follows another text node; this is not a `VerificationCheck`, as html5ever never parses HTML into such a DOM, so it can only happen to a DOM that was built or changed by hand.
 	fn recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type(&self, context: &Path) -> Result<(), HtmlError>;
 	
+	/// Removes unused CSS, as `purify_stylesheet()` does, from every `<style>` element in place, using this HTML DOM to find the CSS that is used.
+	/// The `<style>` elements are purified together, so an `@font-face` or `@keyframes` rule in one is kept if it is referred to from another.
+	/// Fails, leaving every `<style>` element unchanged, if the CSS of any of them does not parse.
+	#[inline(always)]
+	fn purify_style_elements(&mut self, context: &Path, safelist: &PurifySafelist) -> Result<(), HtmlError>;
+	
+	/// Moves CSS from `<style>` elements into `style` attributes, as is needed for HTML email.
+	/// Declarations are merged with any existing `style` attribute following the cascade, ie by `!important`, then specificity (a `style` attribute is the most specific), then source order.
+	/// Rules that can not be inlined, eg `@media` rules, `@font-face` rules and those with selectors such as `a:hover` or `p::first-line`, are kept in the first `<style>` element; all others are removed.
+	/// `<style>` elements with a `media` attribute are left as they are.
+	/// Fails, leaving the HTML DOM unchanged, if the CSS of any `<style>` element or `style` attribute does not parse.
+	#[inline(always)]
+	fn inline_style_elements(&mut self, context: &Path) -> Result<(), HtmlError>;
+	
 	/// Adds this node preceded by a HTML5 DOCTYPE.
 	/// Panics if the unattached_node is not called 'html'.
 	#[inline(always)]
@@ -125,40 +293,63 @@ pub trait RcDomExt: Sized + Minify
 	#[inline(always)]
 	fn _verify_has_no_errors(&self, context: &Path) -> Result<(), HtmlError>;
 	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _verify_has_no_parse_errors(&self, context: &Path, diagnostics: &[ParseDiagnostic]) -> Result<(), HtmlError>;
+	
 	#[doc(hidden)]
 	#[inline(always)]
 	fn _verify_has_no_quirks(&self, context: &Path) -> Result<(), HtmlError>;
 	
 	#[doc(hidden)]
 	#[inline(always)]
-	fn _verify_root_element(&self, context: &Path) -> Result<(), HtmlError>;
+	fn _verify_root_element(&self, context: &Path, policy: &VerificationPolicy, report: &mut VerificationReport) -> Result<(), HtmlError>;
 	
 	#[doc(hidden)]
 	#[inline(always)]
-	fn _parser() -> Utf8LossyDecoder<Parser<RcDom>>
+	fn _move_fragment_root_children_to_document_node(self) -> Self;
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _parser(options: &HtmlParseOptions) -> Utf8LossyDecoder<Parser<RcDom>>
 	{
 		let tree_sink = RcDom::default();
-		let parse_options = ParseOpts
+		let parser = parse_document(tree_sink, options.to_parse_opts());
+		parser.from_utf8()
+	}
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _diagnostics_parser(options: &HtmlParseOptions) -> Utf8LossyDecoder<Parser<ParseDiagnosticsTreeSink>>
+	{
+		let tree_sink = ParseDiagnosticsTreeSink::default();
+		let parser = parse_document(tree_sink, options.to_parse_opts());
+		parser.from_utf8()
+	}
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _source_locations_parser(source: &[u8], options: &HtmlParseOptions) -> Utf8LossyDecoder<SourceLocationsParser>
+	{
+		SourceLocationsParser::new(source, options).from_utf8()
+	}
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _fragment_parser(context: QualName, options: &HtmlParseOptions) -> Utf8LossyDecoder<Parser<RcDom>>
+	{
+		// html5ever only recognises a context element in the HTML namespace, so local-only names (as made by `LocalNameExt.qual_name()`) are moved into it.
+		let context = if context.ns == ns!()
 		{
-			tokenizer: TokenizerOpts
-			{
-				exact_errors: true,
-				discard_bom: true,
-				profile: false,
-				initial_state: None,
-				last_start_tag_name: None,
-			},
-			tree_builder: TreeBuilderOpts
-			{
-				exact_errors: true,
-				scripting_enabled: true,
-				iframe_srcdoc: false,
-				drop_doctype: false,
-				ignore_missing_rules: false,
-				quirks_mode: QuirksMode::NoQuirks,
-			},
+			QualName::new(context.prefix, ns!(html), context.local)
+		}
+		else
+		{
+			context
 		};
-		let parser = parse_document(tree_sink, parse_options);
+		
+		let tree_sink = RcDom::default();
+		let parser = parse_fragment(tree_sink, options.to_parse_opts(), context, vec![]);
 		parser.from_utf8()
 	}
 }
@@ -170,17 +361,93 @@ impl RcDomExt for RcDom
 	{
 		let path = html_document_file_path.as_ref();
 		
-		let document = Self::from_file_path(path)?;
-		document.verify(path)?;
+		let (document, diagnostics) = Self::from_file_path_with_diagnostics(path)?;
+		document.verify_with_diagnostics(path, &diagnostics)?;
 		document.recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type(path)?;
 		Ok(document)
 	}
 	
+	#[inline(always)]
+	fn from_file_path_verified_with_policy_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<P: AsRef<Path>>(html_document_file_path: P, policy: &VerificationPolicy) -> Result<(Self, VerificationReport), HtmlError>
+	{
+		let path = html_document_file_path.as_ref();
+		
+		let (document, diagnostics) = Self::from_file_path_with_diagnostics(path)?;
+		let report = document.verify_with_diagnostics_and_policy(path, &diagnostics, policy)?;
+		document.recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type(path)?;
+		Ok((document, report))
+	}
+	
 	#[inline(always)]
 	fn from_file_path<P: AsRef<Path>>(html_document_file_path: P) -> Result<Self, HtmlError>
 	{
 		let path = html_document_file_path.as_ref();
-		let document = Self::_parser().from_file(path).context(path)?;
+		Self::from_file_path_with_options(path, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_file_path_with_options<P: AsRef<Path>>(file_path: P, options: &HtmlParseOptions) -> Result<Self, HtmlError>
+	{
+		let path = file_path.as_ref();
+		let document = Self::_parser(options).from_file(path).context(path)?;
+		Ok(document)
+	}
+	
+	#[inline(always)]
+	fn from_file_path_with_diagnostics<P: AsRef<Path>>(file_path: P) -> Result<(Self, Vec<ParseDiagnostic>), HtmlError>
+	{
+		Self::from_file_path_with_diagnostics_and_options(file_path, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_file_path_with_diagnostics_and_options<P: AsRef<Path>>(file_path: P, options: &HtmlParseOptions) -> Result<(Self, Vec<ParseDiagnostic>), HtmlError>
+	{
+		let path = file_path.as_ref();
+		let document_and_diagnostics = Self::_diagnostics_parser(options).from_file(path).context(path)?;
+		Ok(document_and_diagnostics)
+	}
+	
+	#[inline(always)]
+	fn from_file_path_with_source_locations<P: AsRef<Path>>(file_path: P) -> Result<(Self, SourceLocations), HtmlError>
+	{
+		Self::from_file_path_with_source_locations_and_options(file_path, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_file_path_with_source_locations_and_options<P: AsRef<Path>>(file_path: P, options: &HtmlParseOptions) -> Result<(Self, SourceLocations), HtmlError>
+	{
+		let path = file_path.as_ref();
+		let bytes = read(path).context(path)?;
+		Ok(Self::from_bytes_with_source_locations_and_options(&bytes, options))
+	}
+	
+	#[inline(always)]
+	fn from_reader_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<R: Read, P: AsRef<Path>>(reader: R, context: P) -> Result<Self, HtmlError>
+	{
+		Self::from_reader_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type_and_options(reader, context, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_reader_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type_and_options<R: Read, P: AsRef<Path>>(mut reader: R, context: P, options: &HtmlParseOptions) -> Result<Self, HtmlError>
+	{
+		let path = context.as_ref();
+		let (document, diagnostics) = Self::_diagnostics_parser(options).read_from(&mut reader).context(path)?;
+		document.verify_with_diagnostics(path, &diagnostics)?;
+		document.recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type(path)?;
+		Ok(document)
+	}
+	
+	#[inline(always)]
+	fn from_reader<R: Read, P: AsRef<Path>>(reader: R, context: P) -> Result<Self, HtmlError>
+	{
+		Self::from_reader_with_options(reader, context, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_reader_with_options<R: Read, P: AsRef<Path>>(mut reader: R, context: P, options: &HtmlParseOptions) -> Result<Self, HtmlError>
+	{
+		let path = context.as_ref();
+		let document = Self::_parser(options).read_from(&mut reader).context(path)?;
 		Ok(document)
 	}
 	
@@ -188,25 +455,175 @@ impl RcDomExt for RcDom
 	fn from_bytes_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<P: AsRef<Path>>(bytes: &[u8], context: P) -> Result<Self, HtmlError>
 	{
 		let path = context.as_ref();
-		let document = Self::from_bytes(bytes);
-		document.verify(path)?;
+		let (document, diagnostics) = Self::from_bytes_with_diagnostics(bytes);
+		document.verify_with_diagnostics(path, &diagnostics)?;
 		document.recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type(path)?;
 		Ok(document)
 	}
 	
+	#[inline(always)]
+	fn from_bytes_verified_with_policy_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type<P: AsRef<Path>>(bytes: &[u8], context: P, policy: &VerificationPolicy) -> Result<(Self, VerificationReport), HtmlError>
+	{
+		let path = context.as_ref();
+		let (document, diagnostics) = Self::from_bytes_with_diagnostics(bytes);
+		let report = document.verify_with_diagnostics_and_policy(path, &diagnostics, policy)?;
+		document.recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type(path)?;
+		Ok((document, report))
+	}
+	
 	#[inline(always)]
 	fn from_bytes(bytes: &[u8]) -> Self
 	{
-		Self::_parser().one(bytes)
+		Self::from_bytes_with_options(bytes, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_bytes_with_options(bytes: &[u8], options: &HtmlParseOptions) -> Self
+	{
+		Self::_parser(options).one(bytes)
+	}
+	
+	#[inline(always)]
+	fn from_bytes_with_diagnostics(bytes: &[u8]) -> (Self, Vec<ParseDiagnostic>)
+	{
+		Self::from_bytes_with_diagnostics_and_options(bytes, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_bytes_with_diagnostics_and_options(bytes: &[u8], options: &HtmlParseOptions) -> (Self, Vec<ParseDiagnostic>)
+	{
+		Self::_diagnostics_parser(options).one(bytes)
+	}
+	
+	#[inline(always)]
+	fn from_bytes_with_source_locations(bytes: &[u8]) -> (Self, SourceLocations)
+	{
+		Self::from_bytes_with_source_locations_and_options(bytes, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_bytes_with_source_locations_and_options(bytes: &[u8], options: &HtmlParseOptions) -> (Self, SourceLocations)
+	{
+		Self::_source_locations_parser(bytes, options).one(bytes)
+	}
+	
+	#[inline(always)]
+	fn from_file_path_sniffing_character_encoding<P: AsRef<Path>>(file_path: P, transport_layer_charset: Option<&str>) -> Result<(Self, SniffedCharacterEncoding), HtmlError>
+	{
+		Self::from_file_path_sniffing_character_encoding_with_options(file_path, transport_layer_charset, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_file_path_sniffing_character_encoding_with_options<P: AsRef<Path>>(file_path: P, transport_layer_charset: Option<&str>, options: &HtmlParseOptions) -> Result<(Self, SniffedCharacterEncoding), HtmlError>
+	{
+		let path = file_path.as_ref();
+		let bytes = read(path).context(path)?;
+		Ok(Self::from_bytes_sniffing_character_encoding_with_options(&bytes, transport_layer_charset, options))
+	}
+	
+	#[inline(always)]
+	fn from_bytes_sniffing_character_encoding(bytes: &[u8], transport_layer_charset: Option<&str>) -> (Self, SniffedCharacterEncoding)
+	{
+		Self::from_bytes_sniffing_character_encoding_with_options(bytes, transport_layer_charset, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_bytes_sniffing_character_encoding_with_options(bytes: &[u8], transport_layer_charset: Option<&str>, options: &HtmlParseOptions) -> (Self, SniffedCharacterEncoding)
+	{
+		let sniffed_character_encoding = SniffedCharacterEncoding::sniff(bytes, transport_layer_charset);
+		let (decoded, had_malformed_byte_sequences) = sniffed_character_encoding.decode(bytes);
+		
+		let mut document = Self::_parser(options).one(decoded.as_bytes());
+		if had_malformed_byte_sequences
+		{
+			document.errors.push(Cow::Owned(format!("Malformed byte sequences for character encoding '{}'", sniffed_character_encoding.encoding.name())));
+		}
+		(document, sniffed_character_encoding)
+	}
+	
+	#[inline(always)]
+	fn from_fragment_file_path<P: AsRef<Path>>(file_path: P, context: QualName) -> Result<Self, HtmlError>
+	{
+		Self::from_fragment_file_path_with_options(file_path, context, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_fragment_file_path_with_options<P: AsRef<Path>>(file_path: P, context: QualName, options: &HtmlParseOptions) -> Result<Self, HtmlError>
+	{
+		let path = file_path.as_ref();
+		let document = Self::_fragment_parser(context, options).from_file(path).context(path)?;
+		Ok(document._move_fragment_root_children_to_document_node())
+	}
+	
+	#[inline(always)]
+	fn from_fragment_bytes(bytes: &[u8], context: QualName) -> Self
+	{
+		Self::from_fragment_bytes_with_options(bytes, context, &HtmlParseOptions::default())
+	}
+	
+	#[inline(always)]
+	fn from_fragment_bytes_with_options(bytes: &[u8], context: QualName, options: &HtmlParseOptions) -> Self
+	{
+		Self::_fragment_parser(context, options).one(bytes)._move_fragment_root_children_to_document_node()
+	}
+	
+	#[inline(always)]
+	fn detach_document_node_children(&mut self) -> Vec<Rc<Node>>
+	{
+		let children = replace(&mut *self.document.children.borrow_mut(), Vec::new());
+		for child in children.iter()
+		{
+			child.parent.set(None);
+		}
+		children
+	}
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _move_fragment_root_children_to_document_node(mut self) -> Self
+	{
+		// html5ever parses a fragment into a 'html' root element that is a child of the document node.
+		let document = self.document.clone();
+		if let Some(root) = document.first_child()
+		{
+			self.reparent_children(&root, &document);
+			self.remove_from_parent(&root);
+		}
+		self
 	}
 	
 	#[inline(always)]
 	fn verify(&self, context: &Path) -> Result<(), HtmlError>
 	{
+		self.verify_with_policy(context, &VerificationPolicy::default()).map(|_| ())
+	}
+	
+	#[inline(always)]
+	fn verify_with_diagnostics(&self, context: &Path, diagnostics: &[ParseDiagnostic]) -> Result<(), HtmlError>
+	{
+		self.verify_with_diagnostics_and_policy(context, diagnostics, &VerificationPolicy::default()).map(|_| ())
+	}
+	
+	#[inline(always)]
+	fn verify_with_policy(&self, context: &Path, policy: &VerificationPolicy) -> Result<VerificationReport, HtmlError>
+	{
+		let mut report = VerificationReport::default();
+		self._verify_is_document_and_not_a_fragment(context)?;
+		policy.apply(VerificationCheck::ParseErrors, self._verify_has_no_errors(context), &mut report)?;
+		policy.apply(VerificationCheck::Quirks, self._verify_has_no_quirks(context), &mut report)?;
+		self._verify_root_element(context, policy, &mut report)?;
+		Ok(report)
+	}
+	
+	#[inline(always)]
+	fn verify_with_diagnostics_and_policy(&self, context: &Path, diagnostics: &[ParseDiagnostic], policy: &VerificationPolicy) -> Result<VerificationReport, HtmlError>
+	{
+		let mut report = VerificationReport::default();
 		self._verify_is_document_and_not_a_fragment(context)?;
-		self._verify_has_no_errors(context)?;
-		self._verify_has_no_quirks(context)?;
-		self._verify_root_element(context)
+		policy.apply(VerificationCheck::ParseErrors, self._verify_has_no_parse_errors(context, diagnostics), &mut report)?;
+		policy.apply(VerificationCheck::Quirks, self._verify_has_no_quirks(context), &mut report)?;
+		self._verify_root_element(context, policy, &mut report)?;
+		Ok(report)
 	}
 	
 	fn recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type(&self, context: &Path) -> Result<(), HtmlError>
@@ -229,6 +646,37 @@ impl RcDomExt for RcDom
 		Ok(())
 	}
 	
+	fn purify_style_elements(&mut self, context: &Path, safelist: &PurifySafelist) -> Result<(), HtmlError>
+	{
+		let style_elements: Vec<Rc<Node>> = self.select_all(&parse_css_selector("style").unwrap()).collect();
+		
+		let mut stylesheets = Vec::with_capacity(style_elements.len());
+		for style_element in style_elements.iter()
+		{
+			stylesheets.push(CascadeResolver::parse_style_element(style_element, context)?);
+		}
+		StylesheetPurifier::purify(&mut stylesheets, from_ref(self), safelist);
+		let purified_css: Vec<String> = stylesheets.iter().map(|stylesheet| stylesheet.to_css_string(false)).collect();
+		
+		for (style_element, css) in style_elements.iter().zip(purified_css.iter())
+		{
+			let children = style_element.children.borrow().clone();
+			for child in children.iter()
+			{
+				child.remove(self);
+			}
+			style_element.append_text(self, css);
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn inline_style_elements(&mut self, context: &Path) -> Result<(), HtmlError>
+	{
+		CssInliner::inline(self, context)
+	}
+	
 	#[inline(always)]
 	fn create_html5_document(&mut self, unattached_node: UnattachedNode) -> Rc<Node>
 	{
@@ -421,7 +869,23 @@ impl RcDomExt for RcDom
 		}
 		else
 		{
-			Err(HtmlError::InvalidFile(context.to_path_buf(), format!("HTML parsed with errors '{:?}'", self.errors)))
+			let diagnostics = self.errors.iter().map(|error| ParseDiagnostic::error(None, error.clone())).collect();
+			Err(HtmlError::ParseErrors(context.to_path_buf(), diagnostics))
+		}
+	}
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn _verify_has_no_parse_errors(&self, context: &Path, diagnostics: &[ParseDiagnostic]) -> Result<(), HtmlError>
+	{
+		let errors: Vec<ParseDiagnostic> = diagnostics.iter().filter(|diagnostic| diagnostic.is_error()).cloned().collect();
+		if errors.is_empty()
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(HtmlError::ParseErrors(context.to_path_buf(), errors))
 		}
 	}
 	
@@ -443,40 +907,44 @@ impl RcDomExt for RcDom
 	
 	#[doc(hidden)]
 	#[inline(always)]
-	fn _verify_root_element(&self, context: &Path) -> Result<(), HtmlError>
+	fn _verify_root_element(&self, context: &Path, policy: &VerificationPolicy, report: &mut VerificationReport) -> Result<(), HtmlError>
 	{
+		use self::VerificationCheck::*;
+		
+		let invalid = |reason: String| Err(HtmlError::InvalidFile(context.to_path_buf(), reason));
+		
 		let mut has_doc_type = false;
 		let mut has_html_root = false;
 		for child_of_document in self.document.children.borrow().iter()
 		{
 			match child_of_document.data
 			{
-				Text { .. } => return Err(HtmlError::InvalidFile(context.to_path_buf(), "Text nodes are not allowed in the root".to_owned())),
+				Text { .. } => policy.apply(RootElement, invalid("Text nodes are not allowed in the root".to_owned()), report)?,
 				
-				Document => return Err(HtmlError::InvalidFile(context.to_path_buf(), "Document nodes are not allowed in the root".to_owned())),
+				Document => policy.apply(RootElement, invalid("Document nodes are not allowed in the root".to_owned()), report)?,
 				
 				Doctype { ref name, ref public_id, ref system_id } =>
 				{
 					if has_doc_type
 					{
-						return Err(HtmlError::InvalidFile(context.to_path_buf(), "multiple DOCTYPE".to_owned()));
+						policy.apply(RootElement, invalid("multiple DOCTYPE".to_owned()), report)?;
 					}
 					has_doc_type = true;
 					if has_html_root
 					{
-						return Err(HtmlError::InvalidFile(context.to_path_buf(), "DOCTYPE after html root".to_owned()));
+						policy.apply(RootElement, invalid("DOCTYPE after html root".to_owned()), report)?;
 					}
 					if !name.eq_ignore_ascii_case("html")
 					{
-						return Err(HtmlError::InvalidFile(context.to_path_buf(), format!("Non html DOCTYPE '{}' found in document root", name)));
+						policy.apply(RootElement, invalid(format!("Non html DOCTYPE '{}' found in document root", name)), report)?;
 					}
 					if !public_id.is_empty()
 					{
-						return Err(HtmlError::InvalidFile(context.to_path_buf(), format!("Non empty DOCTYPE public id '{}' found in document root", public_id)));
+						policy.apply(DocTypePublicId, invalid(format!("Non empty DOCTYPE public id '{}' found in document root", public_id)), report)?;
 					}
 					if !system_id.is_empty()
 					{
-						return Err(HtmlError::InvalidFile(context.to_path_buf(), format!("Non empty DOCTYPE system id '{}' found in document root", system_id)));
+						policy.apply(DocTypeSystemId, invalid(format!("Non empty DOCTYPE system id '{}' found in document root", system_id)), report)?;
 					}
 				},
 				
@@ -484,11 +952,11 @@ impl RcDomExt for RcDom
 				{
 					if !name.is_only_local(&local_name!("html"))
 					{
-						return Err(HtmlError::InvalidFile(context.to_path_buf(), format!("Non html-element '{:?}' found in document root", name)));
+						policy.apply(RootElement, invalid(format!("Non html-element '{:?}' found in document root", name)), report)?;
 					}
 					if has_html_root
 					{
-						return Err(HtmlError::InvalidFile(context.to_path_buf(), "Multiple html elements in document root".to_owned()));
+						policy.apply(RootElement, invalid("Multiple html elements in document root".to_owned()), report)?;
 					}
 					has_html_root = true;
 				}
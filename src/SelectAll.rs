@@ -0,0 +1,53 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A lazy iterator over the element nodes that match a selector, in document order; returned by `Selectable::select_all()`.
+#[derive(Clone)]
+pub struct SelectAll
+{
+	selector: OurSelector,
+	stack: Vec<Rc<Node>>,
+}
+
+impl Debug for SelectAll
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "SelectAll {{ selector: {:?}, remaining_subtrees: {} }}", self.selector, self.stack.len())
+	}
+}
+
+impl Iterator for SelectAll
+{
+	type Item = Rc<Node>;
+	
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		while let Some(node) = self.stack.pop()
+		{
+			self.stack.extend(node.children.borrow().iter().rev().cloned());
+			
+			if node.matches(&self.selector)
+			{
+				return Some(node);
+			}
+		}
+		None
+	}
+}
+
+impl SelectAll
+{
+	// `subtree_roots` are in document order; they and their descendants are searched.
+	#[inline(always)]
+	fn new<I: DoubleEndedIterator<Item=Rc<Node>>>(selector: &OurSelector, subtree_roots: I) -> Self
+	{
+		Self
+		{
+			selector: selector.clone(),
+			stack: subtree_roots.rev().collect(),
+		}
+	}
+}
@@ -21,6 +21,38 @@ pub trait Selectable
 	/// For the RcDom and &'a [RcDom], is currently always false.
 	#[inline]
 	fn matches(&self, selector: &OurSelector) -> bool;
+	
+	/// Returns whether this element matches this selector, using `dynamic_pseudo_class_policy` for pseudo-classes such as `:hover`.
+	/// `matches()` uses `DynamicPseudoClassPolicy::AlwaysMatch`.
+	/// For the RcDom and &'a [RcDom], is currently always false.
+	#[inline]
+	fn matches_with_dynamic_pseudo_class_policy(&self, selector: &OurSelector, dynamic_pseudo_class_policy: &DynamicPseudoClassPolicy) -> bool;
+	
+	/// Lazily finds descendant element nodes that match this selector, in document order.
+	/// Like `querySelectorAll()`, this node itself is excluded; for a list of child nodes, the children themselves are included.
+	#[inline]
+	fn select_all(&self, selector: &OurSelector) -> SelectAll;
+	
+	/// Finds the first descendant element node that matches this selector, in document order, like `querySelector()`.
+	#[inline]
+	fn select_first(&self, selector: &OurSelector) -> Option<Rc<Node>>
+	{
+		self.select_all(selector).next()
+	}
+	
+	/// Parses `selector` then lazily finds descendant element nodes that match it; see `select_all()`.
+	#[inline]
+	fn select_all_str(&self, selector: &str) -> Result<SelectAll, InvalidSelector>
+	{
+		Ok(self.select_all(&InvalidSelector::parse(selector)?))
+	}
+	
+	/// Parses `selector` then finds the first descendant element node that matches it; see `select_first()`.
+	#[inline]
+	fn select_first_str(&self, selector: &str) -> Result<Option<Rc<Node>>, InvalidSelector>
+	{
+		Ok(self.select_first(&InvalidSelector::parse(selector)?))
+	}
 }
 
 /// Use this to match a list of HTML documents.
@@ -64,6 +96,25 @@ impl<'a> Selectable for &'a [RcDom]
 		}
 		false
 	}
+	
+	#[inline]
+	fn matches_with_dynamic_pseudo_class_policy(&self, selector: &OurSelector, dynamic_pseudo_class_policy: &DynamicPseudoClassPolicy) -> bool
+	{
+		for rc_dom in self.iter()
+		{
+			if rc_dom.matches_with_dynamic_pseudo_class_policy(selector, dynamic_pseudo_class_policy)
+			{
+				return true;
+			}
+		}
+		false
+	}
+	
+	#[inline]
+	fn select_all(&self, selector: &OurSelector) -> SelectAll
+	{
+		SelectAll::new(selector, self.iter().flat_map(|rc_dom| rc_dom.document.children.borrow().clone()))
+	}
 }
 
 impl Selectable for RcDom
@@ -85,6 +136,18 @@ impl Selectable for RcDom
 	{
 		self.document.matches(selector)
 	}
+	
+	#[inline]
+	fn matches_with_dynamic_pseudo_class_policy(&self, selector: &OurSelector, dynamic_pseudo_class_policy: &DynamicPseudoClassPolicy) -> bool
+	{
+		self.document.matches_with_dynamic_pseudo_class_policy(selector, dynamic_pseudo_class_policy)
+	}
+	
+	#[inline]
+	fn select_all(&self, selector: &OurSelector) -> SelectAll
+	{
+		SelectAll::new(selector, self.document.children.borrow().iter().cloned())
+	}
 }
 
 impl<'a> Selectable for Rc<Node>
@@ -126,17 +189,31 @@ impl<'a> Selectable for Rc<Node>
 	
 	#[inline]
 	fn matches(&self, selector: &OurSelector) -> bool
+	{
+		self.matches_with_dynamic_pseudo_class_policy(selector, &DynamicPseudoClassPolicy::default())
+	}
+	
+	#[inline]
+	fn matches_with_dynamic_pseudo_class_policy(&self, selector: &OurSelector, dynamic_pseudo_class_policy: &DynamicPseudoClassPolicy) -> bool
 	{
 		match self.data
 		{
 			NodeData::Element { .. } => matches(selector, &ElementNode
 			{
 				node: self.clone(),
+				dynamic_pseudo_class_policy: dynamic_pseudo_class_policy.clone(),
+				scope_root: None,
 			}),
 			
 			_ => false,
 		}
 	}
+	
+	#[inline]
+	fn select_all(&self, selector: &OurSelector) -> SelectAll
+	{
+		SelectAll::new(selector, self.children.borrow().iter().cloned())
+	}
 }
 
 /// Use this to match on the children of a Rc<Node>, eg node.children.matches()
@@ -180,4 +257,23 @@ impl Selectable for RefCell<Vec<Rc<Node>>>
 		}
 		false
 	}
+	
+	#[inline]
+	fn matches_with_dynamic_pseudo_class_policy(&self, selector: &OurSelector, dynamic_pseudo_class_policy: &DynamicPseudoClassPolicy) -> bool
+	{
+		for node in self.borrow().iter()
+		{
+			if node.matches_with_dynamic_pseudo_class_policy(selector, dynamic_pseudo_class_policy)
+			{
+				return true;
+			}
+		}
+		false
+	}
+	
+	#[inline]
+	fn select_all(&self, selector: &OurSelector) -> SelectAll
+	{
+		SelectAll::new(selector, self.borrow().iter().cloned())
+	}
 }
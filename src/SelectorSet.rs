@@ -0,0 +1,186 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Matches many selectors against HTML DOMs in a single walk of each DOM, eg to find the unused rules of a stylesheet.
+///
+/// Selectors are indexed by a key from their rightmost compound selector (an id, otherwise a class, otherwise a local name) so that only the selectors that could match an element are tried against it.
+/// A Bloom filter of the ancestors of the current element quickly rejects selectors whose ancestor compound selectors can not match.
+/// Selectors match as they do for `Selectable::matches()`, except that a selector with a pseudo-element, eg `p::before`, matches the element it originates from, ie `p`.
+#[derive(Debug, Default)]
+pub struct SelectorSet
+{
+	selectors: Vec<(OurSelector, AncestorHashes, MatchingMode)>,
+	by_identifier: HashMap<String, Vec<usize>>,
+	by_class: HashMap<String, Vec<usize>>,
+	by_local_name: HashMap<String, Vec<usize>>,
+	universal: Vec<usize>,
+}
+
+impl SelectorSet
+{
+	/// Adds a selector, returning its index in the `SelectorSetMatches` returned by `matches()`.
+	pub fn insert(&mut self, selector: OurSelector) -> usize
+	{
+		let index = self.selectors.len();
+		
+		let mut identifier = None;
+		let mut class = None;
+		let mut local_name = None;
+		let mut matching_mode = MatchingMode::Normal;
+		let mut iterator = selector.iter();
+		loop
+		{
+			for component in iterator.by_ref()
+			{
+				match *component
+				{
+					Component::ID(ref atom) => identifier = Some(atom.to_string()),
+					Component::Class(ref atom) => if class.is_none()
+					{
+						class = Some(atom.to_string())
+					},
+					Component::LocalName(ref name) => local_name = Some((name.name.to_string(), name.lower_name.to_string())),
+					Component::PseudoElement(..) => matching_mode = MatchingMode::ForStatelessPseudoElement,
+					_ => (),
+				}
+			}
+			
+			// The key of a selector with a pseudo-element comes from the compound selector of the element it originates from.
+			match iterator.next_sequence()
+			{
+				Some(Combinator::PseudoElement) => continue,
+				_ => break,
+			}
+		}
+		
+		match (identifier, class, local_name)
+		{
+			(Some(identifier), _, _) => self.by_identifier.entry(identifier).or_insert_with(Vec::new).push(index),
+			(None, Some(class), _) => self.by_class.entry(class).or_insert_with(Vec::new).push(index),
+			(None, None, Some((local_name, lower_local_name))) =>
+			{
+				// HTML elements are matched by the lower case local name, but foreign elements, eg SVG's `linearGradient`, by the local name as written.
+				if local_name != lower_local_name
+				{
+					self.by_local_name.entry(local_name).or_insert_with(Vec::new).push(index);
+				}
+				self.by_local_name.entry(lower_local_name).or_insert_with(Vec::new).push(index)
+			}
+			(None, None, None) => self.universal.push(index),
+		}
+		
+		let ancestor_hashes = AncestorHashes::new(&selector, SelectorQuirksMode::NoQuirks);
+		self.selectors.push((selector, ancestor_hashes, matching_mode));
+		index
+	}
+	
+	/// The number of selectors.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.selectors.len()
+	}
+	
+	/// Are there no selectors?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.selectors.is_empty()
+	}
+	
+	/// The selector with this index.
+	#[inline(always)]
+	pub fn get(&self, index: usize) -> Option<&OurSelector>
+	{
+		self.selectors.get(index).map(|&(ref selector, _, _)| selector)
+	}
+	
+	/// Matches every selector against every element of `rc_doms`, in document order.
+	pub fn matches(&self, rc_doms: &[RcDom]) -> SelectorSetMatches
+	{
+		let mut selector_set_matches = SelectorSetMatches::new(self.selectors.len());
+		let mut bloom_filter = BloomFilter::new();
+		for rc_dom in rc_doms.iter()
+		{
+			for child in rc_dom.document.children.borrow().iter()
+			{
+				self.match_node(child, &mut bloom_filter, &mut selector_set_matches);
+			}
+		}
+		selector_set_matches
+	}
+	
+	fn match_node(&self, node: &Rc<Node>, bloom_filter: &mut BloomFilter, selector_set_matches: &mut SelectorSetMatches)
+	{
+		let hashes = match node.data
+		{
+			NodeData::Element { ref name, ref attrs, .. } =>
+			{
+				let attributes = attrs.borrow();
+				let attribute = |local_name: LocalName| attributes.iter().find(|attribute| attribute.name.is_only_local(&local_name)).map(|attribute| attribute.value.to_string());
+				let identifier = attribute(local_name!("id")).filter(|identifier| !identifier.is_empty());
+				let mut classes: Vec<String> = attribute(local_name!("class")).map(|classes| classes.split(SELECTOR_WHITESPACE).filter(|class| !class.is_empty()).map(|class| class.to_owned()).collect()).unwrap_or_default();
+				classes.sort();
+				classes.dedup();
+				
+				let mut candidates: Vec<usize> = self.universal.clone();
+				let mut add_candidates = |index: &HashMap<String, Vec<usize>>, key: &str| if let Some(indices) = index.get(key)
+				{
+					candidates.extend_from_slice(indices)
+				};
+				if let Some(ref identifier) = identifier
+				{
+					add_candidates(&self.by_identifier, identifier);
+				}
+				for class in classes.iter()
+				{
+					add_candidates(&self.by_class, class);
+				}
+				add_candidates(&self.by_local_name, &name.local);
+				
+				let element = ElementNode::new(node.clone());
+				for candidate in candidates
+				{
+					let (ref selector, ref ancestor_hashes, matching_mode) = self.selectors[candidate];
+					let mut context = MatchingContext::new(matching_mode, Some(bloom_filter), None, SelectorQuirksMode::NoQuirks);
+					if matches_selector(selector, 0, Some(ancestor_hashes), &element, &mut context, &mut |_, _| {})
+					{
+						selector_set_matches.record(candidate, node);
+					}
+				}
+				
+				let mut hashes = vec![Self::hash(&name.local), Self::hash(&name.ns)];
+				hashes.extend(identifier.iter().map(|identifier| Self::hash(identifier)));
+				hashes.extend(classes.iter().map(|class| Self::hash(class)));
+				hashes
+			}
+			
+			_ => Vec::new(),
+		};
+		
+		for hash in hashes.iter()
+		{
+			bloom_filter.insert_hash(*hash);
+		}
+		
+		for child in node.children.borrow().iter()
+		{
+			self.match_node(child, bloom_filter, selector_set_matches);
+		}
+		
+		for hash in hashes.iter()
+		{
+			bloom_filter.remove_hash(*hash);
+		}
+	}
+	
+	// The same hash as the `PrecomputedHash` of the css crate's `Atom`, which is used for the `AncestorHashes` of selectors.
+	#[inline(always)]
+	fn hash(value: &str) -> u32
+	{
+		let mut state = DefaultHasher::new();
+		value.to_owned().hash(&mut state);
+		state.finish() as u32
+	}
+}
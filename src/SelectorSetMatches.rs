@@ -0,0 +1,59 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Which selectors of a `SelectorSet` matched, and the element nodes they matched, in document order; returned by `SelectorSet::matches()`.
+#[derive(Clone)]
+pub struct SelectorSetMatches
+{
+	matched_nodes: Vec<Vec<Rc<Node>>>,
+}
+
+impl Debug for SelectorSetMatches
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		let number_of_matched_nodes: Vec<usize> = self.matched_nodes.iter().map(|matched_nodes| matched_nodes.len()).collect();
+		write!(f, "SelectorSetMatches {{ number_of_matched_nodes: {:?} }}", number_of_matched_nodes)
+	}
+}
+
+impl SelectorSetMatches
+{
+	/// Did the selector with this index match any element?
+	#[inline(always)]
+	pub fn is_matched(&self, index: usize) -> bool
+	{
+		!self.nodes(index).is_empty()
+	}
+	
+	/// The element nodes that the selector with this index matched, in document order.
+	#[inline(always)]
+	pub fn nodes(&self, index: usize) -> &[Rc<Node>]
+	{
+		&self.matched_nodes[index]
+	}
+	
+	/// The indices of the selectors that did not match any element, eg unused CSS.
+	#[inline(always)]
+	pub fn unmatched(&self) -> Vec<usize>
+	{
+		(0 .. self.matched_nodes.len()).filter(|index| !self.is_matched(*index)).collect()
+	}
+	
+	#[inline(always)]
+	fn new(number_of_selectors: usize) -> Self
+	{
+		Self
+		{
+			matched_nodes: vec![Vec::new(); number_of_selectors],
+		}
+	}
+	
+	#[inline(always)]
+	fn record(&mut self, index: usize, node: &Rc<Node>)
+	{
+		self.matched_nodes[index].push(node.clone());
+	}
+}
@@ -0,0 +1,68 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// The character encoding chosen for some HTML bytes by the HTML 5 specification's encoding sniffing algorithm, and how confident that choice is.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SniffedCharacterEncoding
+{
+	/// The character encoding, eg `windows-1252` or `Shift_JIS`.
+	pub encoding: &'static Encoding,
+	
+	/// How confident the choice of `encoding` is.
+	pub confidence: CharacterEncodingConfidence,
+}
+
+impl SniffedCharacterEncoding
+{
+	/// Determines the character encoding of HTML bytes following the HTML 5 specification's [encoding sniffing algorithm](https://html.spec.whatwg.org/multipage/parsing.html#encoding-sniffing-algorithm), in order:-
+	///
+	/// * a byte order mark (BOM) for UTF-8, UTF-16LE or UTF-16BE;
+	/// * the `transport_layer_charset`, if any and if it is a known label, eg the `charset` parameter of a HTTP `Content-Type` header;
+	/// * a `<meta charset>` or `<meta http-equiv="Content-Type" content="...; charset=...">` found by prescanning the first 1024 bytes;
+	/// * otherwise, `windows-1252`, which the specification recommends as the default for most locales.
+	///
+	/// Autodetection of the encoding from the content and changing the encoding because of a `<meta>` element found after the first 1024 bytes are not supported.
+	pub fn sniff(bytes: &[u8], transport_layer_charset: Option<&str>) -> Self
+	{
+		use self::CharacterEncodingConfidence::*;
+		
+		if let Some((encoding, _byte_order_mark_length)) = Encoding::for_bom(bytes)
+		{
+			return Self::new(encoding, Certain);
+		}
+		
+		if let Some(transport_layer_charset) = transport_layer_charset
+		{
+			if let Some(encoding) = Encoding::for_label(transport_layer_charset.as_bytes())
+			{
+				return Self::new(encoding, Certain);
+			}
+		}
+		
+		if let Some(encoding) = prescan_a_byte_stream_to_determine_its_encoding(bytes)
+		{
+			return Self::new(encoding, Tentative);
+		}
+		
+		Self::new(WINDOWS_1252, Tentative)
+	}
+	
+	/// Decodes bytes using this encoding, removing any byte order mark.
+	/// Malformed byte sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`; the second value of the result is true if any were found.
+	#[inline(always)]
+	pub fn decode<'a>(&self, bytes: &'a [u8]) -> (Cow<'a, str>, bool)
+	{
+		self.encoding.decode_with_bom_removal(bytes)
+	}
+	
+	#[inline(always)]
+	fn new(encoding: &'static Encoding, confidence: CharacterEncodingConfidence) -> Self
+	{
+		Self
+		{
+			encoding,
+			confidence,
+		}
+	}
+}
@@ -0,0 +1,30 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Where in the HTML source a node started.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceLocation
+{
+	/// One-based line number.
+	/// For elements, comments and DOCTYPEs, this is the line of the opening `<`, even if the markup is split over several lines.
+	/// For text nodes and for elements implied by the HTML parsing rules (eg a `<tbody>` that is not in the source), it is the line the tokenizer was on when the node was created.
+	pub line: u64,
+	
+	/// One-based column number, counted in characters, of the opening `<` of an element's start tag, a comment or a DOCTYPE.
+	/// `None` for text nodes and for elements implied by the HTML parsing rules, as html5ever's tokenizer only tracks lines.
+	pub column: Option<u64>,
+}
+
+impl Display for SourceLocation
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		match self.column
+		{
+			None => write!(f, "{}", self.line),
+			Some(column) => write!(f, "{}:{}", self.line, column),
+		}
+	}
+}
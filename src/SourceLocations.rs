@@ -0,0 +1,49 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A side table of where in the HTML source each element, text, comment, processing instruction and DOCTYPE node started.
+/// Create one by parsing with the `RcDomExt` methods ending in `_with_source_locations` and query it with `NodeExt.source_location()`.
+/// Nodes created or merged after parsing (eg by `recursively_strip_nodes_of_comments_and_processing_instructions_and_create_sane_doc_type()`) have no source location.
+#[derive(Debug, Default, Clone)]
+pub struct SourceLocations
+{
+	// The weak reference stops the node's allocation, and so its address, being re-used whilst this table exists.
+	locations: HashMap<*const Node, (Weak<Node>, SourceLocation)>,
+}
+
+impl SourceLocations
+{
+	/// Where in the HTML source `node` started, if known.
+	#[inline(always)]
+	pub fn get(&self, node: &Rc<Node>) -> Option<SourceLocation>
+	{
+		self.locations.get(&(node.deref() as *const Node)).map(|&(_, source_location)| source_location)
+	}
+	
+	/// Number of nodes with a source location.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.locations.len()
+	}
+	
+	/// Are there no nodes with a source location?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.locations.is_empty()
+	}
+	
+	#[inline(always)]
+	fn record(&mut self, node: &Rc<Node>, source_location: SourceLocation)
+	{
+		self.locations.insert(node.deref() as *const Node, (Rc::downgrade(node), source_location));
+	}
+	
+	#[inline(always)]
+	fn record_if_absent(&mut self, node: &Rc<Node>, source_location: SourceLocation)
+	{
+		self.locations.entry(node.deref() as *const Node).or_insert_with(|| (Rc::downgrade(node), source_location));
+	}
+}
@@ -0,0 +1,64 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Parses HTML with a `SourceLocationsTreeSink`, telling it which start tag the tree builder is processing so that elements the tree builder creates on its own do not take the location of a start tag.
+/// Use the `RcDomExt` methods ending in `_with_source_locations` rather than using this directly.
+pub struct SourceLocationsParser
+{
+	tokenizer: Tokenizer<SourceLocationsTokenSink>,
+	input_buffer: BufferQueue,
+}
+
+impl TendrilSink<UTF8> for SourceLocationsParser
+{
+	type Output = (RcDom, SourceLocations);
+	
+	#[inline(always)]
+	fn process(&mut self, tendril: StrTendril)
+	{
+		self.input_buffer.push_back(tendril);
+		while let TokenizerResult::Script(_) = self.tokenizer.feed(&mut self.input_buffer)
+		{
+		}
+	}
+	
+	#[inline(always)]
+	fn error(&mut self, description: Cow<'static, str>)
+	{
+		self.tokenizer.sink.tree_builder.sink.parse_error(description)
+	}
+	
+	#[inline(always)]
+	fn finish(mut self) -> Self::Output
+	{
+		while let TokenizerResult::Script(_) = self.tokenizer.feed(&mut self.input_buffer)
+		{
+		}
+		self.tokenizer.end();
+		self.tokenizer.sink.tree_builder.sink.finish()
+	}
+}
+
+impl SourceLocationsParser
+{
+	/// Creates a new instance for parsing `source` with `options`.
+	#[inline(always)]
+	pub fn new(source: &[u8], options: &HtmlParseOptions) -> Self
+	{
+		let parse_options = options.to_parse_opts();
+		let tree_builder = TreeBuilder::new(SourceLocationsTreeSink::new(source, options), parse_options.tree_builder);
+		Self
+		{
+			tokenizer: Tokenizer::new(SourceLocationsTokenSink { tree_builder }, parse_options.tokenizer),
+			input_buffer: BufferQueue::new(),
+		}
+	}
+	
+	/// Wraps this parser into a `TendrilSink` that accepts UTF-8 bytes, decoding lossily.
+	#[inline(always)]
+	pub fn from_utf8(self) -> Utf8LossyDecoder<Self>
+	{
+		Utf8LossyDecoder::new(self)
+	}
+}
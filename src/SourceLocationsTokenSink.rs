@@ -0,0 +1,39 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// Sits between the tokenizer and the tree builder to tell the `SourceLocationsTreeSink` which start tag, if any, is being processed.
+struct SourceLocationsTokenSink
+{
+	tree_builder: TreeBuilder<Rc<Node>, SourceLocationsTreeSink>,
+}
+
+impl TokenSink for SourceLocationsTokenSink
+{
+	type Handle = Rc<Node>;
+	
+	#[inline(always)]
+	fn process_token(&mut self, token: Token, line_number: u64) -> TokenSinkResult<Self::Handle>
+	{
+		let start_tag = match token
+		{
+			TagToken(Tag { kind: StartTag, ref name, .. }) => Some(name.clone()),
+			_ => None,
+		};
+		self.tree_builder.sink.processing_start_tag(start_tag);
+		self.tree_builder.process_token(token, line_number)
+	}
+	
+	#[inline(always)]
+	fn end(&mut self)
+	{
+		self.tree_builder.sink.processing_start_tag(None);
+		self.tree_builder.end()
+	}
+	
+	#[inline(always)]
+	fn adjusted_current_node_present_but_not_in_html_namespace(&self) -> bool
+	{
+		self.tree_builder.adjusted_current_node_present_but_not_in_html_namespace()
+	}
+}
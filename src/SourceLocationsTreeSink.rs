@@ -0,0 +1,295 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A tree sink that wraps a `RcDom` and records where in the HTML source every node other than the document node started.
+/// Parsing with this sink produces both the `RcDom` and the `SourceLocations`.
+/// Create it with `new()` and parse with a `SourceLocationsParser` to record the line and column of the start of every start tag, comment and DOCTYPE; the `Default` instance only records the line the tokenizer was on.
+/// Elements the tree builder creates without a start tag of their own, such as an implied `<tbody>` or a reconstructed `<b>`, only have a line.
+/// Use the `RcDomExt` methods ending in `_with_source_locations` rather than using this directly.
+pub struct SourceLocationsTreeSink
+{
+	rc_dom: RcDom,
+	current_line: u64,
+	markup_starts: MarkupStarts,
+	current_start_tag: Option<LocalName>,
+	current_start_tag_element: Option<Rc<Node>>,
+	current_start_tag_markup_start: Option<(u64, u64)>,
+	source_locations: SourceLocations,
+}
+
+impl Default for SourceLocationsTreeSink
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			rc_dom: RcDom::default(),
+			current_line: 1,
+			markup_starts: MarkupStarts::default(),
+			current_start_tag: None,
+			current_start_tag_element: None,
+			current_start_tag_markup_start: None,
+			source_locations: SourceLocations::default(),
+		}
+	}
+}
+
+impl SourceLocationsTreeSink
+{
+	/// Creates a new instance for parsing `source` with `options`.
+	#[inline(always)]
+	pub fn new(source: &[u8], options: &HtmlParseOptions) -> Self
+	{
+		Self
+		{
+			markup_starts: MarkupStarts::scan(source, options),
+			.. Self::default()
+		}
+	}
+}
+
+impl TreeSink for SourceLocationsTreeSink
+{
+	type Handle = Rc<Node>;
+	
+	type Output = (RcDom, SourceLocations);
+	
+	#[inline(always)]
+	fn finish(self) -> Self::Output
+	{
+		(self.rc_dom, self.source_locations)
+	}
+	
+	#[inline(always)]
+	fn parse_error(&mut self, msg: Cow<'static, str>)
+	{
+		self.rc_dom.parse_error(msg)
+	}
+	
+	#[inline(always)]
+	fn get_document(&mut self) -> Self::Handle
+	{
+		self.rc_dom.get_document()
+	}
+	
+	#[inline(always)]
+	fn elem_name<'a>(&'a self, target: &'a Self::Handle) -> ExpandedName<'a>
+	{
+		self.rc_dom.elem_name(target)
+	}
+	
+	#[inline(always)]
+	fn create_element(&mut self, name: QualName, attrs: Vec<Attribute>, flags: ElementFlags) -> Self::Handle
+	{
+		let is_current_start_tag = match self.current_start_tag
+		{
+			Some(ref start_tag) => start_tag.eq_ignore_ascii_case(&name.local),
+			None => false,
+		};
+		
+		let markup_start = if is_current_start_tag
+		{
+			// Active formatting elements are reconstructed before the element for the start tag itself is created, so an earlier element with the same name is one of them.
+			match self.current_start_tag_element.take()
+			{
+				Some(reconstructed) =>
+				{
+					self.source_locations.record(&reconstructed, SourceLocation { line: self.current_line, column: None });
+					self.current_start_tag_markup_start
+				}
+				None => self.markup_starts.take(&MarkupStartKind::StartTag(name.local.to_ascii_lowercase().to_string()), self.current_line),
+			}
+		}
+		else
+		{
+			None
+		};
+		
+		let node = self.rc_dom.create_element(name, attrs, flags);
+		self.record_starting_at(&node, markup_start);
+		if is_current_start_tag
+		{
+			self.current_start_tag_element = Some(node.clone());
+			self.current_start_tag_markup_start = markup_start;
+		}
+		node
+	}
+	
+	#[inline(always)]
+	fn create_comment(&mut self, text: StrTendril) -> Self::Handle
+	{
+		let markup_start = self.markup_starts.take(&MarkupStartKind::Comment, self.current_line);
+		let node = self.rc_dom.create_comment(text);
+		self.record_starting_at(&node, markup_start);
+		node
+	}
+	
+	#[inline(always)]
+	fn create_pi(&mut self, target: StrTendril, data: StrTendril) -> Self::Handle
+	{
+		let node = self.rc_dom.create_pi(target, data);
+		self.record(&node);
+		node
+	}
+	
+	#[inline(always)]
+	fn append(&mut self, parent: &Self::Handle, child: NodeOrText<Self::Handle>)
+	{
+		self.rc_dom.append(parent, child);
+		
+		// A new text node is only created if the text could not be merged into an existing one.
+		let last_child = parent.children.borrow().last().cloned();
+		self.record_optional(last_child)
+	}
+	
+	#[inline(always)]
+	fn append_based_on_parent_node(&mut self, element: &Self::Handle, prev_element: &Self::Handle, child: NodeOrText<Self::Handle>)
+	{
+		let has_parent = element.parent().is_some();
+		self.rc_dom.append_based_on_parent_node(element, prev_element, child);
+		
+		let appended = if has_parent
+		{
+			element.previous_sibling(false)
+		}
+		else
+		{
+			prev_element.children.borrow().last().cloned()
+		};
+		self.record_optional(appended)
+	}
+	
+	#[inline(always)]
+	fn append_doctype_to_document(&mut self, name: StrTendril, public_id: StrTendril, system_id: StrTendril)
+	{
+		self.rc_dom.append_doctype_to_document(name, public_id, system_id);
+		
+		let markup_start = self.markup_starts.take(&MarkupStartKind::Doctype, self.current_line);
+		let doctype = self.rc_dom.document.children.borrow().last().cloned();
+		if let Some(doctype) = doctype
+		{
+			self.record_starting_at(&doctype, markup_start)
+		}
+	}
+	
+	#[inline(always)]
+	fn mark_script_already_started(&mut self, node: &Self::Handle)
+	{
+		self.rc_dom.mark_script_already_started(node)
+	}
+	
+	#[inline(always)]
+	fn pop(&mut self, node: &Self::Handle)
+	{
+		self.rc_dom.pop(node)
+	}
+	
+	#[inline(always)]
+	fn get_template_contents(&mut self, target: &Self::Handle) -> Self::Handle
+	{
+		self.rc_dom.get_template_contents(target)
+	}
+	
+	#[inline(always)]
+	fn same_node(&self, x: &Self::Handle, y: &Self::Handle) -> bool
+	{
+		self.rc_dom.same_node(x, y)
+	}
+	
+	#[inline(always)]
+	fn set_quirks_mode(&mut self, mode: QuirksMode)
+	{
+		self.rc_dom.set_quirks_mode(mode)
+	}
+	
+	#[inline(always)]
+	fn append_before_sibling(&mut self, sibling: &Self::Handle, new_node: NodeOrText<Self::Handle>)
+	{
+		self.rc_dom.append_before_sibling(sibling, new_node);
+		
+		let appended = sibling.previous_sibling(false);
+		self.record_optional(appended)
+	}
+	
+	#[inline(always)]
+	fn add_attrs_if_missing(&mut self, target: &Self::Handle, attrs: Vec<Attribute>)
+	{
+		self.rc_dom.add_attrs_if_missing(target, attrs)
+	}
+	
+	#[inline(always)]
+	fn associate_with_form(&mut self, target: &Self::Handle, form: &Self::Handle, nodes: (&Self::Handle, Option<&Self::Handle>))
+	{
+		self.rc_dom.associate_with_form(target, form, nodes)
+	}
+	
+	#[inline(always)]
+	fn remove_from_parent(&mut self, target: &Self::Handle)
+	{
+		self.rc_dom.remove_from_parent(target)
+	}
+	
+	#[inline(always)]
+	fn reparent_children(&mut self, node: &Self::Handle, new_parent: &Self::Handle)
+	{
+		self.rc_dom.reparent_children(node, new_parent)
+	}
+	
+	#[inline(always)]
+	fn is_mathml_annotation_xml_integration_point(&self, handle: &Self::Handle) -> bool
+	{
+		self.rc_dom.is_mathml_annotation_xml_integration_point(handle)
+	}
+	
+	#[inline(always)]
+	fn set_current_line(&mut self, line_number: u64)
+	{
+		self.current_line = line_number;
+		self.rc_dom.set_current_line(line_number)
+	}
+	
+	#[inline(always)]
+	fn complete_script(&mut self, node: &Self::Handle) -> NextParserState
+	{
+		self.rc_dom.complete_script(node)
+	}
+}
+
+impl SourceLocationsTreeSink
+{
+	#[inline(always)]
+	fn processing_start_tag(&mut self, start_tag: Option<LocalName>)
+	{
+		self.current_start_tag = start_tag;
+		self.current_start_tag_element = None;
+		self.current_start_tag_markup_start = None;
+	}
+	
+	#[inline(always)]
+	fn record(&mut self, node: &Rc<Node>)
+	{
+		self.record_starting_at(node, None)
+	}
+	
+	#[inline(always)]
+	fn record_starting_at(&mut self, node: &Rc<Node>, markup_start: Option<(u64, u64)>)
+	{
+		let source_location = match markup_start
+		{
+			None => SourceLocation { line: self.current_line, column: None },
+			Some((line, column)) => SourceLocation { line, column: Some(column) },
+		};
+		self.source_locations.record_if_absent(node, source_location)
+	}
+	
+	#[inline(always)]
+	fn record_optional(&mut self, node: Option<Rc<Node>>)
+	{
+		if let Some(node) = node
+		{
+			self.record(&node)
+		}
+	}
+}
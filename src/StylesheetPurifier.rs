@@ -0,0 +1,234 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+struct StylesheetPurifier<'a>
+{
+	selector_set_matches: SelectorSetMatches,
+	safelist: &'a PurifySafelist,
+	next_selector_index: usize,
+	font_families: HashSet<String>,
+	animation_names: HashSet<String>,
+}
+
+impl<'a> StylesheetPurifier<'a>
+{
+	// Stylesheets are purified together, so that an `@font-face` or `@keyframes` rule in one is kept if it is referred to by another, or by a `style` attribute in `rc_doms`.
+	fn purify(stylesheets: &mut [Stylesheet], rc_doms: &[RcDom], safelist: &'a PurifySafelist)
+	{
+		let mut selector_set = SelectorSet::default();
+		for stylesheet in stylesheets.iter()
+		{
+			Self::insert_selectors(&stylesheet.rules, &mut selector_set);
+		}
+		
+		let mut this = Self
+		{
+			selector_set_matches: selector_set.matches(rc_doms),
+			safelist,
+			next_selector_index: 0,
+			font_families: HashSet::new(),
+			animation_names: HashSet::new(),
+		};
+		
+		for stylesheet in stylesheets.iter_mut()
+		{
+			this.remove_unused_selectors(&mut stylesheet.rules);
+		}
+		
+		for stylesheet in stylesheets.iter()
+		{
+			this.find_referenced_font_families_and_animation_names(&stylesheet.rules);
+		}
+		this.find_font_families_and_animation_names_referenced_by_style_attributes(rc_doms);
+		
+		for stylesheet in stylesheets.iter_mut()
+		{
+			this.remove_unreferenced_font_faces_and_keyframes(&mut stylesheet.rules);
+		}
+	}
+	
+	fn insert_selectors(css_rules: &CssRules, selector_set: &mut SelectorSet)
+	{
+		for css_rule in css_rules.0.iter()
+		{
+			match *css_rule
+			{
+				CssRule::Style(ref style_rule) => for selector in style_rule.selectors.0.iter()
+				{
+					selector_set.insert(selector.clone());
+				},
+				
+				_ => if let Some(nested_css_rules) = Self::nested_css_rules(css_rule)
+				{
+					Self::insert_selectors(nested_css_rules, selector_set)
+				},
+			}
+		}
+	}
+	
+	// Visits selectors in the same order as `insert_selectors()`, so that `next_selector_index` is the index of each selector in `selector_set_matches`.
+	fn remove_unused_selectors(&mut self, css_rules: &mut CssRules)
+	{
+		for css_rule in css_rules.0.iter_mut()
+		{
+			match *css_rule
+			{
+				CssRule::Style(ref mut style_rule) =>
+				{
+					let selectors = replace(&mut style_rule.selectors.0, Vec::new());
+					for selector in selectors
+					{
+						let index = self.next_selector_index;
+						self.next_selector_index += 1;
+						if self.selector_set_matches.is_matched(index) || self.safelist.is_safelisted(&selector)
+						{
+							style_rule.selectors.0.push(selector);
+						}
+					}
+				}
+				
+				_ => if let Some(nested_css_rules) = Self::nested_css_rules_mut(css_rule)
+				{
+					self.remove_unused_selectors(nested_css_rules)
+				},
+			}
+		}
+		
+		Self::remove_empty_rules(css_rules);
+	}
+	
+	fn find_referenced_font_families_and_animation_names(&mut self, css_rules: &CssRules)
+	{
+		for css_rule in css_rules.0.iter()
+		{
+			match *css_rule
+			{
+				CssRule::Style(ref style_rule) => self.find_font_families_and_animation_names_referenced_by(&style_rule.property_declarations),
+				
+				_ => if let Some(nested_css_rules) = Self::nested_css_rules(css_rule)
+				{
+					self.find_referenced_font_families_and_animation_names(nested_css_rules)
+				},
+			}
+		}
+	}
+	
+	// A `style` attribute that does not parse is ignored.
+	fn find_font_families_and_animation_names_referenced_by_style_attributes(&mut self, rc_doms: &[RcDom])
+	{
+		let selector = parse_css_selector("[style]").unwrap();
+		for rc_dom in rc_doms.iter()
+		{
+			for node in rc_dom.select_all(&selector)
+			{
+				if let Some(property_declarations) = node.get_attribute("style").and_then(|style_attribute| CascadeResolver::parse_style_attribute(&style_attribute))
+				{
+					self.find_font_families_and_animation_names_referenced_by(&property_declarations)
+				}
+			}
+		}
+	}
+	
+	fn find_font_families_and_animation_names_referenced_by(&mut self, property_declarations: &PropertyDeclarations<Importance>)
+	{
+		for property_declaration in property_declarations.0.iter()
+		{
+			let value = match property_declaration.value
+			{
+				UnparsedPropertyValue::SpecifiedValue(ref specified_value) => &specified_value.originalCss,
+				UnparsedPropertyValue::CssWideKeyword(_) => continue,
+			};
+			
+			match &property_declaration.name[..]
+			{
+				"font" | "font-family" => Self::insert_font_families(&mut self.font_families, value),
+				"animation" | "animation-name" => Self::insert_animation_names(&mut self.animation_names, value),
+				_ => (),
+			}
+		}
+	}
+	
+	fn remove_unreferenced_font_faces_and_keyframes(&self, css_rules: &mut CssRules)
+	{
+		css_rules.0.retain(|css_rule| match *css_rule
+		{
+			CssRule::FontFace(ref font_face) => match font_face.family
+			{
+				None => true,
+				Some(ref family) => self.font_families.contains(&family.name.to_lowercase()),
+			},
+			
+			CssRule::Keyframes(ref keyframes) => self.animation_names.contains(&keyframes.name.as_atom()[..]),
+			
+			_ => true,
+		});
+		
+		for css_rule in css_rules.0.iter_mut()
+		{
+			if let Some(nested_css_rules) = Self::nested_css_rules_mut(css_rule)
+			{
+				self.remove_unreferenced_font_faces_and_keyframes(nested_css_rules)
+			}
+		}
+		
+		Self::remove_empty_rules(css_rules);
+	}
+	
+	// Over-approximates by treating every run of trailing words of every comma-separated value as a family name, as the `font` shorthand puts sizes and styles before the family.
+	fn insert_font_families(font_families: &mut HashSet<String>, value: &str)
+	{
+		for font_family in value.split(',')
+		{
+			let words: Vec<&str> = font_family.split_whitespace().collect();
+			for start in 0 .. words.len()
+			{
+				let family = words[start ..].join(" ");
+				font_families.insert(family.trim_matches(|character| character == '"' || character == '\'').to_lowercase());
+			}
+		}
+	}
+	
+	// Over-approximates by treating every word of the `animation` shorthand as a name.
+	fn insert_animation_names(animation_names: &mut HashSet<String>, value: &str)
+	{
+		for name in value.split(|character: char| character == ',' || character.is_whitespace()).filter(|name| !name.is_empty())
+		{
+			animation_names.insert(name.trim_matches(|character| character == '"' || character == '\'').to_owned());
+		}
+	}
+	
+	#[inline(always)]
+	fn remove_empty_rules(css_rules: &mut CssRules)
+	{
+		css_rules.0.retain(|css_rule| match *css_rule
+		{
+			CssRule::Style(ref style_rule) => !style_rule.selectors.0.is_empty(),
+			_ => Self::nested_css_rules(css_rule).map(|nested_css_rules| !nested_css_rules.0.is_empty()).unwrap_or(true),
+		});
+	}
+	
+	#[inline(always)]
+	fn nested_css_rules(css_rule: &CssRule) -> Option<&CssRules>
+	{
+		match *css_rule
+		{
+			CssRule::Document(ref document) => Some(&document.rules),
+			CssRule::Media(ref media) => Some(&media.rules),
+			CssRule::Supports(ref supports) => Some(&supports.rules),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn nested_css_rules_mut(css_rule: &mut CssRule) -> Option<&mut CssRules>
+	{
+		match *css_rule
+		{
+			CssRule::Document(ref mut document) => Some(&mut document.rules),
+			CssRule::Media(ref mut media) => Some(&mut media.rules),
+			CssRule::Supports(ref mut supports) => Some(&mut supports.rules),
+			_ => None,
+		}
+	}
+}
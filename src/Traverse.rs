@@ -0,0 +1,83 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A non-recursive depth-first traversal of a node and its descendants, yielding an `Edge::Open` on entering and an `Edge::Close` on leaving each node; returned by `NodeExt::traverse()`.
+#[derive(Clone)]
+pub struct Traverse
+{
+	root: Rc<Node>,
+	skip_inter_element_whitespace_comment_or_processing_instructions: bool,
+	started: bool,
+	stack: Vec<(Rc<Node>, usize)>,
+}
+
+impl Debug for Traverse
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "Traverse {{ skip_inter_element_whitespace_comment_or_processing_instructions: {}, started: {}, depth: {} }}", self.skip_inter_element_whitespace_comment_or_processing_instructions, self.started, self.stack.len())
+	}
+}
+
+impl Iterator for Traverse
+{
+	type Item = Edge;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if !self.started
+		{
+			self.started = true;
+			self.stack.push((self.root.clone(), 0));
+			return Some(Edge::Open(self.root.clone()))
+		}
+		
+		loop
+		{
+			let child =
+			{
+				let &mut (ref node, ref mut next_child_index) = self.stack.last_mut()?;
+				let child = node.children.borrow().get(*next_child_index).cloned();
+				*next_child_index += 1;
+				child
+			};
+			
+			match child
+			{
+				None =>
+				{
+					let (node, _) = self.stack.pop().unwrap();
+					return Some(Edge::Close(node))
+				}
+				
+				Some(child) =>
+				{
+					if self.skip_inter_element_whitespace_comment_or_processing_instructions && child.is_inter_element_whitespace_comment_or_processing_instruction()
+					{
+						continue
+					}
+					self.stack.push((child.clone(), 0));
+					return Some(Edge::Open(child))
+				}
+			}
+		}
+	}
+}
+
+impl Traverse
+{
+	#[inline(always)]
+	fn new(root: &Rc<Node>, skip_inter_element_whitespace_comment_or_processing_instructions: bool) -> Self
+	{
+		Self
+		{
+			root: root.clone(),
+			skip_inter_element_whitespace_comment_or_processing_instructions,
+			started: false,
+			stack: Vec::new(),
+		}
+	}
+}
@@ -0,0 +1,25 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A `role` token that is not a WAI-ARIA 1.2 role; returned when parsing an `AriaRole`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct UnknownAriaRole(pub String);
+
+impl Display for UnknownAriaRole
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "'{}' is not an ARIA role", self.0)
+	}
+}
+
+impl Error for UnknownAriaRole
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"not an ARIA role"
+	}
+}
@@ -0,0 +1,23 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A check made when verifying a HTML DOM.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum VerificationCheck
+{
+	/// The HTML parsed without errors.
+	ParseErrors,
+	
+	/// The HTML did not need quirks mode (or limited quirks mode) to parse.
+	Quirks,
+	
+	/// The document root contains only a single DOCTYPE of 'html', comments, processing instructions and a single 'html' element, in that order.
+	RootElement,
+	
+	/// The DOCTYPE has an empty public id.
+	DocTypePublicId,
+	
+	/// The DOCTYPE has an empty system id.
+	DocTypeSystemId,
+}
@@ -0,0 +1,128 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// What to do when each verification check fails.
+/// The default is what `RcDomExt.verify()` uses: every check is an error.
+/// Use `lenient()` to warn about every check, or the `with_` methods to change individual checks, eg `VerificationPolicy::default().with_quirks(VerificationAction::Warn)`.
+/// A policy only applies to the checks in `VerificationCheck`; even with `lenient()`, verifying a fragment rather than a document, or stripping a DOM in which a node is not a valid child of its parent, always fails.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct VerificationPolicy
+{
+	parse_errors: VerificationAction,
+	quirks: VerificationAction,
+	root_element: VerificationAction,
+	doc_type_public_id: VerificationAction,
+	doc_type_system_id: VerificationAction,
+}
+
+impl Default for VerificationPolicy
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::all(VerificationAction::Error)
+	}
+}
+
+impl VerificationPolicy
+{
+	/// Warns about, rather than fails, every check.
+	/// Useful for minifying third-party HTML that is slightly broken.
+	#[inline(always)]
+	pub fn lenient() -> Self
+	{
+		Self::all(VerificationAction::Warn)
+	}
+	
+	/// What to do if the HTML parsed with errors.
+	#[inline(always)]
+	pub fn with_parse_errors(mut self, action: VerificationAction) -> Self
+	{
+		self.parse_errors = action;
+		self
+	}
+	
+	/// What to do if the HTML needed quirks mode (or limited quirks mode) to parse.
+	#[inline(always)]
+	pub fn with_quirks(mut self, action: VerificationAction) -> Self
+	{
+		self.quirks = action;
+		self
+	}
+	
+	/// What to do if the document root contains anything other than a single DOCTYPE of 'html', comments, processing instructions and a single 'html' element.
+	#[inline(always)]
+	pub fn with_root_element(mut self, action: VerificationAction) -> Self
+	{
+		self.root_element = action;
+		self
+	}
+	
+	/// What to do if the DOCTYPE has a public id.
+	#[inline(always)]
+	pub fn with_doc_type_public_id(mut self, action: VerificationAction) -> Self
+	{
+		self.doc_type_public_id = action;
+		self
+	}
+	
+	/// What to do if the DOCTYPE has a system id.
+	#[inline(always)]
+	pub fn with_doc_type_system_id(mut self, action: VerificationAction) -> Self
+	{
+		self.doc_type_system_id = action;
+		self
+	}
+	
+	/// What to do when `check` fails.
+	#[inline(always)]
+	pub fn action(&self, check: VerificationCheck) -> VerificationAction
+	{
+		use self::VerificationCheck::*;
+		
+		match check
+		{
+			ParseErrors => self.parse_errors,
+			Quirks => self.quirks,
+			RootElement => self.root_element,
+			DocTypePublicId => self.doc_type_public_id,
+			DocTypeSystemId => self.doc_type_system_id,
+		}
+	}
+	
+	#[inline(always)]
+	fn apply(&self, check: VerificationCheck, outcome: Result<(), HtmlError>, report: &mut VerificationReport) -> Result<(), HtmlError>
+	{
+		use self::VerificationAction::*;
+		
+		match self.action(check)
+		{
+			Error => outcome,
+			
+			Warn =>
+			{
+				if let Err(error) = outcome
+				{
+					report.warn(check, error);
+				}
+				Ok(())
+			}
+			
+			Ignore => Ok(()),
+		}
+	}
+	
+	#[inline(always)]
+	fn all(action: VerificationAction) -> Self
+	{
+		Self
+		{
+			parse_errors: action,
+			quirks: action,
+			root_element: action,
+			doc_type_public_id: action,
+			doc_type_system_id: action,
+		}
+	}
+}
@@ -0,0 +1,39 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// The outcome of a verification that did not fail.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct VerificationReport
+{
+	/// Checks that failed but were only warned about, in the order they were made.
+	pub warnings: Vec<VerificationWarning>,
+}
+
+impl VerificationReport
+{
+	/// Did every check pass?
+	#[inline(always)]
+	pub fn is_clean(&self) -> bool
+	{
+		self.warnings.is_empty()
+	}
+	
+	#[inline(always)]
+	fn warn(&mut self, check: VerificationCheck, error: HtmlError)
+	{
+		use self::HtmlError::*;
+		
+		match error
+		{
+			InvalidFile(_, reason) => self.warnings.push(VerificationWarning { check, reason }),
+			
+			ParseErrors(_, diagnostics) => for diagnostic in diagnostics
+			{
+				self.warnings.push(VerificationWarning { check, reason: diagnostic.to_string() })
+			},
+			
+			error @ Io(..) => self.warnings.push(VerificationWarning { check, reason: error.to_string() }),
+		}
+	}
+}
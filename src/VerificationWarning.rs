@@ -0,0 +1,23 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A verification check that failed but which a `VerificationPolicy` said to warn about rather than fail.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VerificationWarning
+{
+	/// The check that failed.
+	pub check: VerificationCheck,
+	
+	/// Why it failed.
+	pub reason: String,
+}
+
+impl Display for VerificationWarning
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{:?}: {}", self.check, self.reason)
+	}
+}
@@ -0,0 +1,33 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// What `AmpTransformer` did.
+#[derive(Default, Clone)]
+pub struct AmpTransformReport
+{
+	/// The AMP elements, eg `<amp-img>`, that replaced the original elements, in document order.
+	pub converted: Vec<Rc<Node>>,
+	
+	/// The elements that could not be converted, in document order.
+	pub not_converted: Vec<AmpUnconvertedElement>,
+}
+
+impl Debug for AmpTransformReport
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("AmpTransformReport").field("converted", &self.converted.len()).field("not_converted", &self.not_converted).finish()
+	}
+}
+
+impl AmpTransformReport
+{
+	/// Was every element converted?
+	#[inline(always)]
+	pub fn is_complete(&self) -> bool
+	{
+		self.not_converted.is_empty()
+	}
+}
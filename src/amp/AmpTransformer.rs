@@ -0,0 +1,166 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Converts common elements to their AMP equivalents: `<img>` to `<amp-img>`, `<video>` to `<amp-video>` and `<iframe>` to `<amp-iframe>`.
+/// Attributes and children are kept.
+/// Elements without a `width` and `height` (which AMP needs to lay out the page before resources load) and `<iframe>` and `<video>` elements whose `src` does not use `https` are not converted, and are reported instead.
+/// Elements inside `<noscript>` are left alone, as they are fallbacks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AmpTransformer
+{
+	add_custom_element_scripts: bool,
+}
+
+impl Default for AmpTransformer
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			add_custom_element_scripts: true,
+		}
+	}
+}
+
+impl AmpTransformer
+{
+	/// Add a `<script async custom-element="..." src="...">` to the `<head>` for each custom element, such as `<amp-video>`, that is converted to?
+	/// Defaults to true.
+	#[inline(always)]
+	pub fn with_add_custom_element_scripts(mut self, add_custom_element_scripts: bool) -> Self
+	{
+		self.add_custom_element_scripts = add_custom_element_scripts;
+		self
+	}
+	
+	/// Converts elements in a HTML DOM to their AMP equivalents, reporting which were converted and which could not be.
+	pub fn transform(&self, rc_dom: &mut RcDom) -> AmpTransformReport
+	{
+		let mut candidates = Vec::new();
+		let mut ancestors = Vec::new();
+		let document = rc_dom.document.clone();
+		Self::find_candidates(&document, &mut ancestors, &mut candidates);
+		
+		let mut report = AmpTransformReport::default();
+		let mut custom_elements_converted_to = Vec::new();
+		for (node, path, amp_local_name) in candidates
+		{
+			match Self::cannot_convert_because(&node, amp_local_name)
+			{
+				Some(reason) => report.not_converted.push(AmpUnconvertedElement { node, path, reason }),
+				
+				None =>
+				{
+					let attributes = match node.data
+					{
+						NodeData::Element { ref attrs, .. } => attrs.borrow().clone(),
+						_ => unreachable!("Only elements are candidates"),
+					};
+					let amp_node = rc_dom.append_new_element_before_sibling_node(&node, LocalName::from(amp_local_name).qual_name(), attributes);
+					rc_dom.move_node_children_to_parent_node(&amp_node, &node);
+					rc_dom.remove_from_parent(&node);
+					
+					if !AmpValidator::is_built_in(amp_local_name) && !custom_elements_converted_to.contains(&amp_local_name)
+					{
+						custom_elements_converted_to.push(amp_local_name);
+					}
+					report.converted.push(amp_node);
+				}
+			}
+		}
+		
+		if self.add_custom_element_scripts
+		{
+			for custom_element in custom_elements_converted_to
+			{
+				Self::add_custom_element_script(rc_dom, custom_element);
+			}
+		}
+		
+		report
+	}
+	
+	fn find_candidates(parent: &Rc<Node>, ancestors: &mut Vec<Rc<Node>>, candidates: &mut Vec<(Rc<Node>, String, &'static str)>)
+	{
+		for child in parent.children.borrow().iter()
+		{
+			let amp_local_name = match child.data
+			{
+				NodeData::Element { ref name, .. } if name.is_unprefixed_and_html_namespace_or_none() => match name.local
+				{
+					local_name!("noscript") => continue,
+					local_name!("img") => Some("amp-img"),
+					local_name!("video") => Some("amp-video"),
+					local_name!("iframe") => Some("amp-iframe"),
+					_ => None,
+				},
+				
+				_ => continue,
+			};
+			
+			if let Some(amp_local_name) = amp_local_name
+			{
+				candidates.push((child.clone(), local_names_path(child, ancestors), amp_local_name));
+			}
+			
+			ancestors.push(child.clone());
+			Self::find_candidates(child, ancestors, candidates);
+			ancestors.pop();
+		}
+	}
+	
+	//noinspection SpellCheckingInspection
+	fn cannot_convert_because(node: &Rc<Node>, amp_local_name: &'static str) -> Option<&'static str>
+	{
+		let has_size = node.has_attribute("width") && node.has_attribute("height");
+		let has_layout_without_size = node.get_attribute("layout").map(|layout| match &layout[..]
+		{
+			"fill" | "container" | "flex-item" | "nodisplay" => true,
+			_ => false,
+		}).unwrap_or(false);
+		if !has_size && !has_layout_without_size
+		{
+			return Some("AMP needs a 'width' and 'height' to lay out this element");
+		}
+		
+		if amp_local_name != "amp-img"
+		{
+			if let Some(src) = node.get_attribute("src")
+			{
+				if !src.starts_with("https://")
+				{
+					return Some("AMP needs the 'src' of this element to use https");
+				}
+			}
+		}
+		
+		if amp_local_name == "amp-iframe" && !node.has_attribute("src") && !node.has_attribute("srcdoc")
+		{
+			return Some("AMP needs a 'src' or 'srcdoc' for an iframe");
+		}
+		
+		None
+	}
+	
+	fn add_custom_element_script(rc_dom: &mut RcDom, custom_element: &'static str)
+	{
+		let head = rc_dom.document.children.borrow().iter().find(|child| child.is_only_local(&local_name!("html"))).and_then(|html| html.children.borrow().iter().find(|child| child.is_only_local(&local_name!("head"))).cloned());
+		
+		if let Some(head) = head
+		{
+			let already_present = head.children.borrow().iter().any(|child| child.is_only_local(&local_name!("script")) && child.get_attribute("custom-element").map(|value| value == custom_element).unwrap_or(false));
+			if !already_present
+			{
+				let attributes = vec!
+				[
+					local_name!("async").empty_attribute(),
+					LocalName::from("custom-element").attribute(custom_element),
+					local_name!("src").attribute(&format!("https://cdn.ampproject.org/v0/{}-0.1.js", custom_element)),
+				];
+				rc_dom.append_new_element_to_parent_node(&head, local_name!("script").qual_name(), attributes);
+			}
+		}
+	}
+}
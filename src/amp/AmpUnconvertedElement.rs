@@ -0,0 +1,35 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// An element that `AmpTransformer` could not convert to its AMP equivalent.
+#[derive(Clone)]
+pub struct AmpUnconvertedElement
+{
+	/// The element, which is left in place.
+	pub node: Rc<Node>,
+	
+	/// The local names of the element's ancestors and itself, eg `html > body > img`.
+	pub path: String,
+	
+	/// Why the element could not be converted.
+	pub reason: &'static str,
+}
+
+impl Debug for AmpUnconvertedElement
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("AmpUnconvertedElement").field("path", &self.path).field("reason", &self.reason).finish()
+	}
+}
+
+impl Display for AmpUnconvertedElement
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}: {}", self.path, self.reason)
+	}
+}
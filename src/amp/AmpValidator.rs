@@ -0,0 +1,284 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Checks that a HTML DOM conforms to the main rules of the [AMP HTML specification](https://amp.dev/documentation/guides-and-tutorials/learn/spec/amphtml/).
+/// This is not a replacement for the official AMP validator; it checks the required markup (`<html ⚡>`, `<meta charset="utf-8">`, the viewport, the boilerplate `<style>` and the runtime `<script>`), disallowed elements and attributes, the size of inline CSS and that custom elements have their scripts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AmpValidator
+{
+	maximum_inline_style_bytes: usize,
+}
+
+impl Default for AmpValidator
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			maximum_inline_style_bytes: Self::MAXIMUM_INLINE_STYLE_BYTES,
+		}
+	}
+}
+
+impl AmpValidator
+{
+	/// The maximum size of the CSS in `<style amp-custom>` and `style` attributes combined that the AMP HTML specification allows.
+	pub const MAXIMUM_INLINE_STYLE_BYTES: usize = 75_000;
+	
+	/// The URL of the AMP runtime `<script>`.
+	pub const RUNTIME_SCRIPT_URL: &'static str = "https://cdn.ampproject.org/v0.js";
+	
+	/// Use a different maximum size for inline CSS, eg to leave room for CSS added later.
+	#[inline(always)]
+	pub fn with_maximum_inline_style_bytes(mut self, maximum_inline_style_bytes: usize) -> Self
+	{
+		self.maximum_inline_style_bytes = maximum_inline_style_bytes;
+		self
+	}
+	
+	/// Validates a HTML DOM, returning every violation found.
+	pub fn validate(&self, rc_dom: &RcDom) -> Vec<AmpViolation>
+	{
+		use self::AmpViolationKind::*;
+		
+		let mut violations = Vec::new();
+		
+		let mut ancestors = Vec::new();
+		let mut inline_style_bytes = 0;
+		let mut custom_elements_used: Vec<(String, Rc<Node>, String)> = Vec::new();
+		let mut custom_element_scripts: Vec<String> = Vec::new();
+		for child in rc_dom.document.children.borrow().iter()
+		{
+			Self::validate_node(child, &mut ancestors, &mut inline_style_bytes, &mut custom_elements_used, &mut custom_element_scripts, &mut violations);
+		}
+		
+		let html = rc_dom.document.children.borrow().iter().find(|child| child.is_only_local(&local_name!("html"))).cloned();
+		let head = html.as_ref().and_then(|html| html.children.borrow().iter().find(|child| child.is_only_local(&local_name!("head"))).cloned());
+		
+		match html
+		{
+			None => violations.push(Self::violation(&rc_dom.document, &[], MissingAmpAttribute)),
+			
+			Some(ref html) => if !html.has_attribute("⚡") && !html.has_attribute("amp")
+			{
+				violations.push(Self::violation(html, &[], MissingAmpAttribute))
+			},
+		}
+		
+		let (missing_markup_node, missing_markup_ancestors) = match (&head, &html)
+		{
+			(&Some(ref head), &Some(ref html)) => (head.clone(), vec![html.clone()]),
+			(&None, &Some(ref html)) => (html.clone(), vec![]),
+			_ => (rc_dom.document.clone(), vec![]),
+		};
+		for &(markup, is_present) in Self::required_markup(head.as_ref()).iter()
+		{
+			if !is_present
+			{
+				violations.push(Self::violation(&missing_markup_node, &missing_markup_ancestors, MissingRequiredMarkup { markup }));
+			}
+		}
+		
+		if inline_style_bytes > self.maximum_inline_style_bytes
+		{
+			violations.push(Self::violation(&missing_markup_node, &missing_markup_ancestors, InlineStyleTooLarge { bytes: inline_style_bytes, maximum_bytes: self.maximum_inline_style_bytes }));
+		}
+		
+		for (custom_element, node, path) in custom_elements_used
+		{
+			if !custom_element_scripts.contains(&custom_element)
+			{
+				violations.push(AmpViolation { node, path, kind: MissingCustomElementScript { custom_element } });
+			}
+		}
+		
+		violations
+	}
+	
+	fn validate_node(node: &Rc<Node>, ancestors: &mut Vec<Rc<Node>>, inline_style_bytes: &mut usize, custom_elements_used: &mut Vec<(String, Rc<Node>, String)>, custom_element_scripts: &mut Vec<String>, violations: &mut Vec<AmpViolation>)
+	{
+		use self::AmpViolationKind::*;
+		
+		let (name, attributes) = match node.data
+		{
+			NodeData::Element { ref name, ref attrs, .. } => (name, attrs.borrow()),
+			
+			Text { ref contents } =>
+			{
+				let is_amp_custom_style = ancestors.last().map(|parent| parent.is_only_local(&local_name!("style")) && parent.has_attribute("amp-custom")).unwrap_or(false);
+				if is_amp_custom_style
+				{
+					*inline_style_bytes += contents.borrow().len();
+				}
+				return
+			}
+			
+			_ => return,
+		};
+		
+		for attribute in attributes.iter()
+		{
+			let attribute_name = &*attribute.name.local;
+			let value = attribute.value.trim_start();
+			
+			let is_disallowed = (attribute_name.starts_with("on") && attribute_name != "on") || attribute_name.starts_with("i-amp-") || attribute_name.starts_with("i-amphtml-") || (value.len() >= 11 && value.as_bytes()[.. 11].eq_ignore_ascii_case(b"javascript:"));
+			if is_disallowed
+			{
+				violations.push(Self::violation(node, ancestors, DisallowedAttribute { attribute: attribute_name.to_owned() }));
+			}
+			
+			if attribute_name == "style"
+			{
+				*inline_style_bytes += attribute.value.len();
+			}
+		}
+		
+		if name.is_unprefixed_and_html_namespace_or_none()
+		{
+			let local = &*name.local;
+			if Self::is_disallowed_element(node, local)
+			{
+				violations.push(Self::violation(node, ancestors, DisallowedElement));
+			}
+			
+			if local.starts_with("amp-") && !Self::is_built_in(local)
+			{
+				custom_elements_used.push((local.to_owned(), node.clone(), local_names_path(node, ancestors)));
+			}
+			
+			if local == "script"
+			{
+				if let Some(custom_element) = node.get_attribute("custom-element").or_else(|| node.get_attribute("custom-template"))
+				{
+					custom_element_scripts.push(custom_element);
+				}
+			}
+		}
+		drop(attributes);
+		
+		ancestors.push(node.clone());
+		for child in node.children.borrow().iter()
+		{
+			Self::validate_node(child, ancestors, inline_style_bytes, custom_elements_used, custom_element_scripts, violations);
+		}
+		ancestors.pop();
+	}
+	
+	//noinspection SpellCheckingInspection
+	fn is_disallowed_element(node: &Rc<Node>, local: &str) -> bool
+	{
+		match local
+		{
+			"applet" | "audio" | "embed" | "frame" | "frameset" | "iframe" | "img" | "object" | "param" | "picture" | "video" => true,
+			
+			"script" =>
+			{
+				let is_json = node.get_attribute("type").map(|type_| type_.eq_ignore_ascii_case("application/ld+json") || type_.eq_ignore_ascii_case("application/json")).unwrap_or(false);
+				let is_async = node.has_attribute("async");
+				let is_runtime = is_async && node.get_attribute("src").map(|src| src == Self::RUNTIME_SCRIPT_URL).unwrap_or(false);
+				let is_custom_element = is_async && (node.has_attribute("custom-element") || node.has_attribute("custom-template")) && node.get_attribute("src").map(|src| src.starts_with("https://cdn.ampproject.org/")).unwrap_or(false);
+				!(is_json || is_runtime || is_custom_element)
+			}
+			
+			"style" => !node.has_attribute("amp-boilerplate") && !node.has_attribute("amp-custom") && !node.has_attribute("amp-keyframes"),
+			
+			"link" =>
+			{
+				let is_stylesheet = node.get_attribute("rel").map(|rel| rel.split_whitespace().any(|rel| rel.eq_ignore_ascii_case("stylesheet"))).unwrap_or(false);
+				is_stylesheet && !node.get_attribute("href").map(|href| Self::is_font_provider(&href)).unwrap_or(false)
+			}
+			
+			"meta" => match node.get_attribute("http-equiv")
+			{
+				None => false,
+				Some(http_equiv) => !["x-ua-compatible", "content-language", "pics-label", "imagetoolbar", "content-style-type", "content-script-type", "origin-trial"].iter().any(|allowed| http_equiv.eq_ignore_ascii_case(allowed)),
+			},
+			
+			_ => false,
+		}
+	}
+	
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	fn is_font_provider(href: &str) -> bool
+	{
+		["https://cloud.typography.com/", "https://fast.fonts.net/", "https://fonts.googleapis.com/", "https://maxcdn.bootstrapcdn.com/font-awesome/", "https://use.fontawesome.com/", "https://use.typekit.net/"].iter().any(|prefix| href.starts_with(prefix))
+	}
+	
+	//noinspection SpellCheckingInspection
+	#[inline(always)]
+	fn is_built_in(local: &str) -> bool
+	{
+		match local
+		{
+			"amp-img" | "amp-layout" | "amp-pixel" => true,
+			_ => false,
+		}
+	}
+	
+	//noinspection SpellCheckingInspection
+	fn required_markup(head: Option<&Rc<Node>>) -> [(&'static str, bool); 5]
+	{
+		let mut has_charset = false;
+		let mut has_viewport = false;
+		let mut has_boilerplate = false;
+		let mut has_noscript_boilerplate = false;
+		let mut has_runtime = false;
+		
+		if let Some(head) = head
+		{
+			let is_boilerplate = |node: &Rc<Node>| node.is_only_local(&local_name!("style")) && node.has_attribute("amp-boilerplate");
+			
+			for child in head.children.borrow().iter()
+			{
+				match child.data
+				{
+					NodeData::Element { ref name, .. } if name.is_unprefixed_and_html_namespace_or_none() => match name.local
+					{
+						local_name!("meta") =>
+						{
+							has_charset |= child.get_attribute("charset").map(|charset| charset.eq_ignore_ascii_case("utf-8")).unwrap_or(false);
+							has_viewport |= child.get_attribute("name").map(|name| name.eq_ignore_ascii_case("viewport")).unwrap_or(false) && child.get_attribute("content").map(|content| content.contains("width=device-width")).unwrap_or(false);
+						}
+						
+						local_name!("style") => has_boilerplate |= is_boilerplate(child),
+						
+						// When scripting is enabled, html5ever parses the content of `<noscript>` as text.
+						local_name!("noscript") => has_noscript_boilerplate |= child.children.borrow().iter().any(|grand_child| match grand_child.data
+						{
+							Text { ref contents } => contents.borrow().contains("amp-boilerplate"),
+							_ => is_boilerplate(grand_child),
+						}),
+						
+						local_name!("script") => has_runtime |= child.has_attribute("async") && child.get_attribute("src").map(|src| src == Self::RUNTIME_SCRIPT_URL).unwrap_or(false),
+						
+						_ => (),
+					},
+					
+					_ => (),
+				}
+			}
+		}
+		
+		[
+			("<meta charset=\"utf-8\">", has_charset),
+			("<meta name=\"viewport\" content=\"width=device-width\">", has_viewport),
+			("<style amp-boilerplate>", has_boilerplate),
+			("<noscript><style amp-boilerplate></noscript>", has_noscript_boilerplate),
+			("<script async src=\"https://cdn.ampproject.org/v0.js\">", has_runtime),
+		]
+	}
+	
+	#[inline(always)]
+	fn violation(node: &Rc<Node>, ancestors: &[Rc<Node>], kind: AmpViolationKind) -> AmpViolation
+	{
+		AmpViolation
+		{
+			node: node.clone(),
+			path: local_names_path(node, ancestors),
+			kind,
+		}
+	}
+}
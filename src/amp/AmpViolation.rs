@@ -0,0 +1,35 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// A node that does not conform to the AMP HTML specification, found by `AmpValidator`.
+#[derive(Clone)]
+pub struct AmpViolation
+{
+	/// The node that does not conform; for missing markup, this is the `<head>` (or `<html>` or document node if that is missing).
+	pub node: Rc<Node>,
+	
+	/// The local names of the node's ancestors and itself, eg `html > body > img`.
+	pub path: String,
+	
+	/// Why the node does not conform.
+	pub kind: AmpViolationKind,
+}
+
+impl Debug for AmpViolation
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("AmpViolation").field("path", &self.path).field("kind", &self.kind).finish()
+	}
+}
+
+impl Display for AmpViolation
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}: {}", self.path, self.kind)
+	}
+}
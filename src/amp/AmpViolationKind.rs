@@ -0,0 +1,69 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Why a HTML DOM does not conform to the [AMP HTML specification](https://amp.dev/documentation/guides-and-tutorials/learn/spec/amphtml/).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum AmpViolationKind
+{
+	/// The `<html>` element lacks the `⚡` or `amp` attribute.
+	MissingAmpAttribute,
+	
+	/// The `<head>` lacks some required markup, eg `<meta charset="utf-8">`, the AMP boilerplate `<style>` or the AMP runtime `<script>`.
+	MissingRequiredMarkup
+	{
+		/// The required markup.
+		markup: &'static str,
+	},
+	
+	/// The element is not allowed in AMP HTML, eg `<img>` (which should be `<amp-img>`), a non-AMP `<script>` or a `<link rel="stylesheet">` that is not for a font provider.
+	DisallowedElement,
+	
+	/// The attribute is not allowed in AMP HTML, eg an event handler such as `onclick` or a `javascript:` URL.
+	DisallowedAttribute
+	{
+		/// The attribute's local name.
+		attribute: String,
+	},
+	
+	/// The CSS in `<style amp-custom>` and `style` attributes is larger than allowed.
+	InlineStyleTooLarge
+	{
+		/// The size of the CSS in bytes.
+		bytes: usize,
+		
+		/// The maximum size allowed in bytes.
+		maximum_bytes: usize,
+	},
+	
+	/// A custom AMP element, eg `<amp-video>`, is used without the `<script async custom-element="amp-video" src="...">` that implements it.
+	MissingCustomElementScript
+	{
+		/// The custom element's name, eg `amp-video`.
+		custom_element: String,
+	},
+}
+
+impl Display for AmpViolationKind
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::AmpViolationKind::*;
+		
+		match *self
+		{
+			MissingAmpAttribute => write!(f, "the html element must have a '⚡' or 'amp' attribute"),
+			
+			MissingRequiredMarkup { markup } => write!(f, "the head must contain {}", markup),
+			
+			DisallowedElement => write!(f, "element is not allowed in AMP HTML"),
+			
+			DisallowedAttribute { ref attribute } => write!(f, "attribute '{}' is not allowed in AMP HTML", attribute),
+			
+			InlineStyleTooLarge { bytes, maximum_bytes } => write!(f, "inline CSS is {} bytes but at most {} bytes are allowed", bytes, maximum_bytes),
+			
+			MissingCustomElementScript { ref custom_element } => write!(f, "custom element '{}' is used without a <script async custom-element=\"{}\">", custom_element, custom_element),
+		}
+	}
+}
@@ -0,0 +1,253 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// The role an element has without a `role` attribute, following the [HTML-AAM](https://www.w3.org/TR/html-aam-1.0/) mappings for ARIA 1.2.
+/// Returns `None` for nodes that are not elements and for elements that have no corresponding role, eg `<label>` or `<head>`.
+/// An explicit `role` attribute on `node` is ignored; the `role` attribute of an ancestor `<table>` is used to distinguish `cell` from `gridcell`.
+//noinspection SpellCheckingInspection
+pub fn implicit_role(node: &Rc<Node>) -> Option<AriaRole>
+{
+	use self::AriaRole::*;
+	
+	let name = match node.data
+	{
+		NodeData::Element { ref name, .. } => name,
+		
+		_ => return None,
+	};
+	
+	if name.ns == ns!(mathml)
+	{
+		return if name.local == local_name!("math")
+		{
+			Some(math)
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	if !name.is_unprefixed_and_html_namespace_or_none()
+	{
+		return None;
+	}
+	
+	let attribute = |attribute_name: &str| node.get_attribute(attribute_name).map(|value| value.to_ascii_lowercase());
+	let has_non_empty_attribute = |attribute_name: &str| attribute(attribute_name).map(|value| !value.trim().is_empty()).unwrap_or(false);
+	let has_accessible_name = || has_non_empty_attribute("aria-label") || has_non_empty_attribute("aria-labelledby") || has_non_empty_attribute("title");
+	let nearest_ancestor = |local_names: &[LocalName]|
+	{
+		let mut ancestor = node.parent();
+		while let Some(node) = ancestor
+		{
+			if node.is_only_local_of(local_names)
+			{
+				return Some(node);
+			}
+			ancestor = node.parent();
+		}
+		None
+	};
+	let is_scoped_to_sectioning_content = || nearest_ancestor(&[local_name!("article"), local_name!("aside"), local_name!("main"), local_name!("nav"), local_name!("section")]).is_some();
+	let is_scoped_to_sectioning_content_other_than_main = || nearest_ancestor(&[local_name!("article"), local_name!("aside"), local_name!("nav"), local_name!("section")]).is_some();
+	let is_in_grid = ||
+	{
+		match nearest_ancestor(&[local_name!("table")])
+		{
+			None => false,
+			Some(ancestor_table) => ancestor_table.get_attribute("role").map(|role| match role.to_ascii_lowercase().split_whitespace().next()
+			{
+				Some("grid") | Some("treegrid") => true,
+				_ => false,
+			}).unwrap_or(false),
+		}
+	};
+	
+	let role = match name.local
+	{
+		local_name!("a") => if node.has_attribute("href")
+		{
+			link
+		}
+		else
+		{
+			generic
+		},
+		
+		local_name!("area") => if node.has_attribute("href")
+		{
+			link
+		}
+		else
+		{
+			return None
+		},
+		
+		local_name!("address") | local_name!("details") | local_name!("fieldset") | local_name!("hgroup") | local_name!("optgroup") => group,
+		
+		local_name!("article") => article,
+		
+		// Unlike `<header>` and `<footer>`, an `<aside>` scoped to `<main>` keeps its role.
+		local_name!("aside") => if !is_scoped_to_sectioning_content_other_than_main() || has_accessible_name()
+		{
+			complementary
+		}
+		else
+		{
+			generic
+		},
+		
+		local_name!("b") | local_name!("bdi") | local_name!("bdo") | local_name!("data") | local_name!("div") | local_name!("i") | local_name!("pre") | local_name!("q") | local_name!("samp") | local_name!("small") | local_name!("span") | local_name!("u") => generic,
+		
+		local_name!("blockquote") => blockquote,
+		
+		local_name!("button") => button,
+		
+		local_name!("caption") => caption,
+		
+		local_name!("code") => code,
+		
+		local_name!("datalist") => listbox,
+		
+		local_name!("dd") => definition,
+		
+		local_name!("del") | local_name!("s") => deletion,
+		
+		local_name!("dfn") | local_name!("dt") => term,
+		
+		local_name!("dialog") => dialog,
+		
+		local_name!("em") => emphasis,
+		
+		local_name!("figure") => figure,
+		
+		local_name!("footer") => if is_scoped_to_sectioning_content()
+		{
+			generic
+		}
+		else
+		{
+			contentinfo
+		},
+		
+		local_name!("form") => form,
+		
+		local_name!("h1") | local_name!("h2") | local_name!("h3") | local_name!("h4") | local_name!("h5") | local_name!("h6") => heading,
+		
+		local_name!("header") => if is_scoped_to_sectioning_content()
+		{
+			generic
+		}
+		else
+		{
+			banner
+		},
+		
+		local_name!("hr") => separator,
+		
+		local_name!("html") => document,
+		
+		local_name!("img") => match attribute("alt")
+		{
+			Some(ref alt) if alt.is_empty() => presentation,
+			_ => img,
+		},
+		
+		local_name!("input") =>
+		{
+			let has_list = node.has_attribute("list");
+			match attribute("type").as_ref().map(|type_| type_.trim()).unwrap_or("text")
+			{
+				"button" | "image" | "reset" | "submit" => button,
+				"checkbox" => checkbox,
+				"radio" => radio,
+				"range" => slider,
+				"number" => spinbutton,
+				"email" | "tel" | "text" | "url" | "search" if has_list => combobox,
+				"search" => searchbox,
+				"color" | "date" | "datetime-local" | "file" | "hidden" | "month" | "password" | "time" | "week" => return None,
+				_ => textbox,
+			}
+		}
+		
+		local_name!("ins") => insertion,
+		
+		local_name!("li") => listitem,
+		
+		local_name!("main") => main,
+		
+		local_name!("menu") | local_name!("ol") | local_name!("ul") => list,
+		
+		local_name!("meter") => meter,
+		
+		local_name!("nav") => navigation,
+		
+		local_name!("option") => option,
+		
+		local_name!("output") => status,
+		
+		local_name!("p") => paragraph,
+		
+		local_name!("progress") => progressbar,
+		
+		local_name!("section") => if has_accessible_name()
+		{
+			region
+		}
+		else
+		{
+			generic
+		},
+		
+		local_name!("select") =>
+		{
+			let size = attribute("size").and_then(|size| size.trim().parse::<u32>().ok()).unwrap_or(0);
+			if node.has_attribute("multiple") || size > 1
+			{
+				listbox
+			}
+			else
+			{
+				combobox
+			}
+		}
+		
+		local_name!("strong") => strong,
+		
+		local_name!("sub") => subscript,
+		
+		local_name!("sup") => superscript,
+		
+		local_name!("table") => table,
+		
+		local_name!("tbody") | local_name!("tfoot") | local_name!("thead") => rowgroup,
+		
+		local_name!("td") => if is_in_grid()
+		{
+			gridcell
+		}
+		else
+		{
+			cell
+		},
+		
+		local_name!("textarea") => textbox,
+		
+		local_name!("th") => match attribute("scope").as_ref().map(|scope| scope.trim())
+		{
+			Some("row") | Some("rowgroup") => rowheader,
+			_ => columnheader,
+		},
+		
+		local_name!("time") => time,
+		
+		local_name!("tr") => row,
+		
+		ref local if &**local == "search" => search,
+		
+		_ => return None,
+	};
+	Some(role)
+}
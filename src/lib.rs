@@ -66,15 +66,28 @@
 
 pub extern crate css;
 pub extern crate either;
+pub extern crate encoding_rs;
 #[macro_use] pub extern crate html5ever;
 #[macro_use] extern crate quick_error;
 
 
 pub use ::css::parse_css_selector;
+use ::css::Stylesheet;
+use ::css::cssparser::ToCss;
+use ::css::domain::CssRule;
+use ::css::domain::CssRules;
+use ::css::domain::StyleRule;
 use ::css::domain::atRules::namespace::NamespaceUrl;
+use ::css::domain::properties::CssWideKeyword;
+use ::css::domain::properties::Importance;
+use ::css::domain::properties::PropertyDeclaration;
+use ::css::domain::properties::PropertyDeclarations;
+use ::css::domain::properties::UnparsedPropertyValue;
+use ::css::domain::selectors::DeduplicatedSelectors;
 use ::css::domain::selectors::matches;
 use ::css::domain::selectors::OurSelector;
 use ::css::domain::selectors::OurSelectorImpl;
+use ::css::domain::selectors::TextDirectionality;
 use ::css::selectors::Element;
 use ::css::selectors::OpaqueElement;
 use ::css::selectors::SelectorImpl;
@@ -82,68 +95,180 @@ use ::css::selectors::attr::AttrSelectorOperation;
 use ::css::selectors::attr::CaseSensitivity;
 use ::css::selectors::attr::NamespaceConstraint;
 use ::css::selectors::attr::SELECTOR_WHITESPACE;
+use ::css::selectors::bloom::BloomFilter;
 use ::css::selectors::matching::ElementSelectorFlags;
 use ::css::selectors::matching::LocalMatchingContext;
 use ::css::selectors::matching::MatchingContext;
+use ::css::selectors::matching::MatchingMode;
+use ::css::selectors::matching::matches_selector;
+use ::css::selectors::matching::QuirksMode as SelectorQuirksMode;
 use ::css::selectors::matching::RelevantLinkStatus;
+use ::css::selectors::parser::AncestorHashes;
+use ::css::selectors::parser::Combinator;
+use ::css::selectors::parser::Component;
 use ::either::Either;
 use ::either::Either::*;
+use ::encoding_rs::Encoding;
+use ::encoding_rs::UTF_16BE;
+use ::encoding_rs::UTF_16LE;
+use ::encoding_rs::UTF_8;
+use ::encoding_rs::WINDOWS_1252;
+use ::encoding_rs::X_USER_DEFINED;
 pub use ::html5ever::Attribute;
+use ::html5ever::ExpandedName;
 pub use ::html5ever::LocalName;
+pub use ::html5ever::Namespace;
 pub use ::html5ever::Parser;
 use ::html5ever::driver::parse_document;
+use ::html5ever::driver::parse_fragment;
 use ::html5ever::driver::ParseOpts;
 pub use ::html5ever::interface::QualName;
 pub use ::html5ever::interface::AppendNode;
 pub use ::html5ever::interface::AppendText;
+use ::html5ever::interface::NodeOrText;
 pub use ::html5ever::rcdom::Node;
 pub use ::html5ever::rcdom::NodeData;
 use ::html5ever::rcdom::NodeData::*;
 pub use ::html5ever::rcdom::RcDom;
+use ::html5ever::serialize::serialize;
+use ::html5ever::serialize::SerializeOpts;
+use ::html5ever::serialize::TraversalScope;
 use ::html5ever::tendril::NonAtomic;
 pub use ::html5ever::tendril::StrTendril;
 use ::html5ever::tendril::Tendril;
 use ::html5ever::tendril::TendrilSink;
 use ::html5ever::tendril::fmt::UTF8;
 use ::html5ever::tendril::stream::Utf8LossyDecoder;
+use ::html5ever::tokenizer::BufferQueue;
+use ::html5ever::tokenizer::StartTag;
+use ::html5ever::tokenizer::Tag;
+use ::html5ever::tokenizer::TagToken;
+use ::html5ever::tokenizer::Token;
+use ::html5ever::tokenizer::Tokenizer;
 use ::html5ever::tokenizer::TokenizerOpts;
+use ::html5ever::tokenizer::TokenizerResult;
+use ::html5ever::tokenizer::TokenSink;
+use ::html5ever::tokenizer::TokenSinkResult;
 use ::html5ever::tree_builder::ElementFlags;
-use ::html5ever::tree_builder::QuirksMode;
+use ::html5ever::tree_builder::NextParserState;
+pub use ::html5ever::tree_builder::QuirksMode;
+use ::html5ever::tree_builder::TreeBuilder;
 use ::html5ever::tree_builder::TreeBuilderOpts;
 pub use ::html5ever::tree_builder::TreeSink;
 use ::quick_error::ResultExt;
+use ::std::borrow::Cow;
 use ::std::cell::Cell;
 use ::std::cell::RefCell;
+use ::std::collections::BTreeMap;
+use ::std::collections::HashMap;
+use ::std::collections::HashSet;
+use ::std::collections::hash_map::DefaultHasher;
+use ::std::error::Error;
 use ::std::fmt;
 use ::std::fmt::Debug;
+use ::std::fmt::Display;
 use ::std::fmt::Formatter;
+use ::std::fs::read;
+use ::std::hash::Hash;
+use ::std::hash::Hasher;
 use ::std::io;
+use ::std::io::Read;
 use ::std::io::Write;
+use ::std::iter::Filter;
+use ::std::mem::replace;
 use ::std::mem::uninitialized;
 use ::std::ops::Deref;
 use ::std::path::Path;
 use ::std::path::PathBuf;
 use ::std::rc::Rc;
+use ::std::rc::Weak;
+use ::std::slice::from_mut;
+use ::std::slice::from_ref;
+use ::std::str::FromStr;
 
 
 #[cfg(test)] mod tests;
 
 
+/// Validation of, and transformation to, AMP HTML.
+pub mod amp;
+
+
+include!("AccessibilityAudit.rs");
+include!("AccessibilityFinding.rs");
+include!("AccessibilityRule.rs");
+include!("AccessibleNameComputation.rs");
+include!("AccessibleNameTraversal.rs");
+include!("Ancestors.rs");
 include!("AriaRole.rs");
+include!("AriaRoleCategory.rs");
 include!("AttributeExt.rs");
+include!("CascadeOrigin.rs");
+include!("CascadeResolver.rs");
+include!("CharacterEncodingConfidence.rs");
+include!("ChildrenElements.rs");
+include!("ClassList.rs");
+include!("ComputedValue.rs");
+include!("ContentCategory.rs");
+include!("ContentModelValidator.rs");
+include!("ContentModelViolation.rs");
+include!("ContentModelViolationKind.rs");
+include!("CssInliner.rs");
+include!("Descendants.rs");
+include!("DescendantsPostOrder.rs");
 include!("Dir.rs");
 include!("Draggable.rs");
+include!("DynamicPseudoClass.rs");
+include!("DynamicPseudoClassPolicy.rs");
+include!("Edge.rs");
+include!("SelectAll.rs");
 include!("Selectable.rs");
+include!("SelectorSet.rs");
+include!("SelectorSetMatches.rs");
 include!("ElementNode.rs");
+include!("FollowingSiblings.rs");
 include!("HtmlError.rs");
+include!("HtmlParseOptions.rs");
+include!("implicit_role.rs");
+include!("InnerText.rs");
+include!("InvalidClass.rs");
+include!("InvalidSelector.rs");
 include!("is_inter_element_whitespace.rs");
 include!("is_space_character.rs");
+include!("local_names_path.rs");
 include!("LocalNameExt.rs");
+include!("MarkupStart.rs");
+include!("MarkupStartKind.rs");
+include!("MarkupStarts.rs");
+include!("MatchedDeclaration.rs");
+include!("MatchedRule.rs");
 include!("Minify.rs");
 include!("NodeExt.rs");
+include!("ParseDiagnostic.rs");
+include!("ParseDiagnosticSeverity.rs");
+include!("ParseDiagnosticsTreeSink.rs");
+include!("parse_scoped_css_selector.rs");
+include!("PrecedingSiblings.rs");
+include!("prescan_a_byte_stream_to_determine_its_encoding.rs");
+include!("purify_stylesheet.rs");
+include!("PurifySafelist.rs");
 include!("QualNameExt.rs");
 include!("QualNameOnlyExt.rs");
 include!("RcDomExt.rs");
+include!("SniffedCharacterEncoding.rs");
+include!("SourceLocation.rs");
+include!("SourceLocations.rs");
+include!("SourceLocationsParser.rs");
+include!("SourceLocationsTokenSink.rs");
+include!("SourceLocationsTreeSink.rs");
+include!("StylesheetPurifier.rs");
+include!("Traverse.rs");
 include!("UltraMinifyingHtmlSerializer.rs");
 include!("UnattachedNode.rs");
 include!("UnattachedNodeExt.rs");
+include!("UnknownAriaRole.rs");
+include!("VerificationAction.rs");
+include!("VerificationCheck.rs");
+include!("VerificationPolicy.rs");
+include!("VerificationReport.rs");
+include!("VerificationWarning.rs");
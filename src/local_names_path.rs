@@ -0,0 +1,28 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// The local names of `ancestors` and `node`, eg `html > body > p > div`, used to locate nodes in DOMs that were not parsed.
+fn local_names_path(node: &Rc<Node>, ancestors: &[Rc<Node>]) -> String
+{
+	#[inline(always)]
+	fn name(node: &Rc<Node>) -> &str
+	{
+		match node.data
+		{
+			NodeData::Document => "#document",
+			NodeData::Element { ref name, .. } => &name.local,
+			Text { .. } => "#text",
+			_ => "#node",
+		}
+	}
+	
+	let mut path = String::new();
+	for ancestor in ancestors.iter()
+	{
+		path.push_str(name(ancestor));
+		path.push_str(" > ");
+	}
+	path.push_str(name(node));
+	path
+}
@@ -0,0 +1,80 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// An identifier that `:scope` is rewritten to.
+// `ElementNode.has_id()` matches it against the scope root and never against `id` attributes, so an element whose `id` happens to be this (eg one written as `id=&#xFFFD;html5ever_ext_scope`) does not match it.
+const SCOPE_IDENTIFIER: &'static str = "\u{FFFD}html5ever_ext_scope";
+
+/// Parses a CSS selector which may use the `:scope` pseudo-class, for use with `NodeExt::matches_within()`.
+/// `parse_css_selector()` rejects `:scope`, so it is rewritten to an identifier selector that matches the scope root.
+/// As a consequence, each `:scope` adds the specificity of an identifier, (1, 0, 0), to the selector's `specificity()` rather than that of a pseudo-class, (0, 1, 0); do not use the specificity of such selectors to order them against others in a cascade.
+pub fn parse_scoped_css_selector(selector: &str) -> Result<OurSelector, InvalidSelector>
+{
+	const SCOPE: &'static str = ":scope";
+	
+	#[inline(always)]
+	fn is_identifier_character(character: char) -> bool
+	{
+		character.is_ascii_alphanumeric() || character == '-' || character == '_' || !character.is_ascii()
+	}
+	
+	let mut rewritten = String::with_capacity(selector.len());
+	let mut quote = None;
+	let mut characters = selector.char_indices().peekable();
+	while let Some((index, character)) = characters.next()
+	{
+		match (quote, character)
+		{
+			(_, '\\') =>
+			{
+				rewritten.push(character);
+				if let Some((_, escaped)) = characters.next()
+				{
+					rewritten.push(escaped);
+				}
+			}
+			
+			(Some(opening_quote), _) =>
+			{
+				if character == opening_quote
+				{
+					quote = None;
+				}
+				rewritten.push(character);
+			}
+			
+			(None, '"') | (None, '\'') =>
+			{
+				quote = Some(character);
+				rewritten.push(character);
+			}
+			
+			(None, ':') =>
+			{
+				let remaining = &selector[index .. ];
+				let is_scope = !rewritten.ends_with(':') && remaining.len() >= SCOPE.len() && remaining.is_char_boundary(SCOPE.len()) && remaining[.. SCOPE.len()].eq_ignore_ascii_case(SCOPE) && !remaining[SCOPE.len() .. ].starts_with(is_identifier_character) && !remaining[SCOPE.len() .. ].starts_with('(');
+				if is_scope
+				{
+					rewritten.push_str("#\\FFFD html5ever_ext_scope");
+					for _ in 1 .. SCOPE.len()
+					{
+						characters.next();
+					}
+				}
+				else
+				{
+					rewritten.push(character);
+				}
+			}
+			
+			_ => rewritten.push(character),
+		}
+	}
+	
+	InvalidSelector::parse(&rewritten).map_err(|invalid_selector| InvalidSelector
+	{
+		selector: selector.to_owned(),
+		reason: invalid_selector.reason,
+	})
+}
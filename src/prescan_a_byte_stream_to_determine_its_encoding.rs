@@ -0,0 +1,306 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+// An implementation of https://html.spec.whatwg.org/multipage/parsing.html#prescan-a-byte-stream-to-determine-its-encoding
+// Returns None if no encoding was found or the prescan ran out of bytes.
+//noinspection SpellCheckingInspection
+fn prescan_a_byte_stream_to_determine_its_encoding(bytes: &[u8]) -> Option<&'static Encoding>
+{
+	const NUMBER_OF_BYTES_TO_PRESCAN: usize = 1024;
+	
+	let bytes = if bytes.len() > NUMBER_OF_BYTES_TO_PRESCAN
+	{
+		&bytes[.. NUMBER_OF_BYTES_TO_PRESCAN]
+	}
+	else
+	{
+		bytes
+	};
+	
+	let length = bytes.len();
+	let mut position = 0;
+	while position < length
+	{
+		let remaining = &bytes[position .. ];
+		
+		if remaining.starts_with(b"<!--")
+		{
+			// "Advance the position pointer so that it points at the first 0x3E byte which is preceded by two 0x2D bytes (i.e. at the end of an ASCII '-->' sequence) and comes after the 0x3C byte that was found. (The two 0x2D bytes can be the same as those in the '<!--' sequence.)"
+			match find_bytes(&bytes[position + 2 .. ], b"-->")
+			{
+				None => return None,
+				Some(index) => position += 2 + index + 2,
+			}
+		}
+		else if remaining.len() > 5 && remaining[.. 5].eq_ignore_ascii_case(b"<meta") && (is_prescan_space(remaining[5]) || remaining[5] == b'/')
+		{
+			position += 5;
+			
+			let mut attribute_names: Vec<Vec<u8>> = Vec::new();
+			let mut got_pragma = false;
+			let mut need_pragma: Option<bool> = None;
+			let mut charset_is_null = true;
+			let mut charset: Option<&'static Encoding> = None;
+			
+			while let Some((name, value)) = get_an_attribute(bytes, &mut position).ok()?
+			{
+				if attribute_names.contains(&name)
+				{
+					continue;
+				}
+				
+				match &name[..]
+				{
+					b"http-equiv" => if &value[..] == b"content-type"
+					{
+						got_pragma = true;
+					},
+					
+					b"content" => if let Some(encoding) = extract_a_character_encoding_from_a_meta_element(&value)
+					{
+						if charset_is_null
+						{
+							charset_is_null = false;
+							charset = Some(encoding);
+							need_pragma = Some(true);
+						}
+					},
+					
+					b"charset" => if charset_is_null
+					{
+						charset_is_null = false;
+						charset = Encoding::for_label(&value);
+						need_pragma = Some(false);
+					},
+					
+					_ => (),
+				}
+				
+				attribute_names.push(name);
+			}
+			
+			let processable = match need_pragma
+			{
+				None => false,
+				Some(true) => got_pragma,
+				Some(false) => true,
+			};
+			
+			if processable
+			{
+				if let Some(encoding) = charset
+				{
+					return Some
+					(
+						if encoding == UTF_16BE || encoding == UTF_16LE
+						{
+							UTF_8
+						}
+						else if encoding == X_USER_DEFINED
+						{
+							WINDOWS_1252
+						}
+						else
+						{
+							encoding
+						}
+					);
+				}
+			}
+		}
+		else if remaining.len() > 1 && remaining[0] == b'<' && (remaining[1].is_ascii_alphabetic() || (remaining.len() > 2 && remaining[1] == b'/' && remaining[2].is_ascii_alphabetic()))
+		{
+			// "Advance the position pointer so that it points at the next 0x09 (HT), 0x0A (LF), 0x0C (FF), 0x0D (CR), 0x20 (SP), or 0x3E (>) byte."
+			while position < length && !is_prescan_space(bytes[position]) && bytes[position] != b'>'
+			{
+				position += 1;
+			}
+			
+			// "Repeatedly get an attribute until no further attributes can be found".
+			while get_an_attribute(bytes, &mut position).ok()?.is_some()
+			{
+			}
+		}
+		else if remaining.starts_with(b"<!") || remaining.starts_with(b"</") || remaining.starts_with(b"<?")
+		{
+			// "Advance the position pointer so that it points at the first 0x3E byte which comes after the 0x3C byte that was found."
+			match find_bytes(remaining, b">")
+			{
+				None => return None,
+				Some(index) => position += index,
+			}
+		}
+		
+		// "Next byte".
+		position += 1;
+	}
+	
+	None
+}
+
+// An implementation of https://html.spec.whatwg.org/multipage/parsing.html#concept-get-attributes-when-sniffing
+// Returns Err(()) if the bytes ran out, which aborts the prescan, or Ok(None) if there is no further attribute.
+fn get_an_attribute(bytes: &[u8], position: &mut usize) -> Result<Option<(Vec<u8>, Vec<u8>)>, ()>
+{
+	macro_rules! current
+	{
+		() =>
+		{
+			match bytes.get(*position)
+			{
+				None => return Err(()),
+				Some(&byte) => byte,
+			}
+		}
+	}
+	
+	while is_prescan_space(current!()) || current!() == b'/'
+	{
+		*position += 1;
+	}
+	
+	if current!() == b'>'
+	{
+		return Ok(None);
+	}
+	
+	let mut attribute_name = Vec::new();
+	let mut attribute_value = Vec::new();
+	
+	loop
+	{
+		match current!()
+		{
+			b'=' if !attribute_name.is_empty() =>
+			{
+				*position += 1;
+				break;
+			}
+			
+			byte if is_prescan_space(byte) =>
+			{
+				while is_prescan_space(current!())
+				{
+					*position += 1;
+				}
+				
+				if current!() != b'='
+				{
+					return Ok(Some((attribute_name, attribute_value)));
+				}
+				
+				*position += 1;
+				break;
+			}
+			
+			b'/' | b'>' => return Ok(Some((attribute_name, attribute_value))),
+			
+			byte => attribute_name.push(byte.to_ascii_lowercase()),
+		}
+		*position += 1;
+	}
+	
+	while is_prescan_space(current!())
+	{
+		*position += 1;
+	}
+	
+	match current!()
+	{
+		quote @ b'"' | quote @ b'\'' =>
+		{
+			loop
+			{
+				*position += 1;
+				let byte = current!();
+				if byte == quote
+				{
+					*position += 1;
+					return Ok(Some((attribute_name, attribute_value)));
+				}
+				attribute_value.push(byte.to_ascii_lowercase());
+			}
+		}
+		
+		b'>' => return Ok(Some((attribute_name, attribute_value))),
+		
+		byte => attribute_value.push(byte.to_ascii_lowercase()),
+	}
+	
+	loop
+	{
+		*position += 1;
+		let byte = current!();
+		if is_prescan_space(byte) || byte == b'>'
+		{
+			return Ok(Some((attribute_name, attribute_value)));
+		}
+		attribute_value.push(byte.to_ascii_lowercase());
+	}
+}
+
+// An implementation of https://html.spec.whatwg.org/multipage/urls-and-fetching.html#algorithm-for-extracting-a-character-encoding-from-a-meta-element
+fn extract_a_character_encoding_from_a_meta_element(value: &[u8]) -> Option<&'static Encoding>
+{
+	const CHARSET: &'static [u8] = b"charset";
+	
+	let length = value.len();
+	let mut position = 0;
+	loop
+	{
+		let index = (position .. length).find(|&index| value[index .. ].len() >= CHARSET.len() && value[index .. index + CHARSET.len()].eq_ignore_ascii_case(CHARSET))?;
+		position = index + CHARSET.len();
+		
+		while position < length && is_prescan_space(value[position])
+		{
+			position += 1;
+		}
+		
+		if position < length && value[position] == b'='
+		{
+			position += 1;
+			break;
+		}
+	}
+	
+	while position < length && is_prescan_space(value[position])
+	{
+		position += 1;
+	}
+	
+	match value.get(position)
+	{
+		None => None,
+		
+		Some(&quote) if quote == b'"' || quote == b'\'' =>
+		{
+			let start = position + 1;
+			let end = start + find_bytes(&value[start .. ], &[quote])?;
+			Encoding::for_label(&value[start .. end])
+		}
+		
+		Some(_) =>
+		{
+			let end = (position .. length).find(|&index| is_prescan_space(value[index]) || value[index] == b';').unwrap_or(length);
+			Encoding::for_label(&value[position .. end])
+		}
+	}
+}
+
+#[inline(always)]
+fn is_prescan_space(byte: u8) -> bool
+{
+	match byte
+	{
+		0x09 | 0x0A | 0x0C | 0x0D | 0x20 => true,
+		
+		_ => false,
+	}
+}
+
+#[inline(always)]
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize>
+{
+	haystack.windows(needle.len()).position(|window| window == needle)
+}
@@ -0,0 +1,18 @@
+// This file is part of html5ever_ext. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of html5ever_ext. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/html5ever_ext/master/COPYRIGHT.
+
+
+/// Removes unused CSS from `stylesheet`, ie the selectors of style rules that match no element in any of `rc_doms`.
+///
+/// Each selector of a selector list is kept or removed on its own; a style rule is removed once all of its selectors have been, and an `@media`, `@supports` or `@document` rule once all of its rules have been.
+/// `@font-face` and `@keyframes` rules are removed if no remaining declaration, nor any `style` attribute in `rc_doms`, refers to their font family or animation name.
+/// Selectors in `safelist` are always kept.
+/// Dynamic pseudo-classes, such as `:hover`, are assumed to match, and a selector with a pseudo-element, such as `p::before`, is kept if the element it originates from is used.
+///
+/// To purify the `<style>` elements of a HTML DOM in place, use `RcDomExt.purify_style_elements()`.
+#[inline(always)]
+pub fn purify_stylesheet(mut stylesheet: Stylesheet, rc_doms: &[RcDom], safelist: &PurifySafelist) -> Stylesheet
+{
+	StylesheetPurifier::purify(from_mut(&mut stylesheet), rc_doms, safelist);
+	stylesheet
+}
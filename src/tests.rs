@@ -37,3 +37,639 @@ fn smoke()
 	
 	eprintln!("{}", rc_dom.debug_string());
 }
+
+#[test]
+fn fragment()
+{
+	let rc_dom = RcDom::from_fragment_str("<li>a<li>b", local_name!("ul").qual_name());
+	
+	let selector = parse_css_selector("li").unwrap();
+	let mut count = 0;
+	rc_dom.find_all_matching_child_nodes_depth_first_excluding_this_one(&selector, &mut |_node|
+	{
+		count += 1;
+		false
+	});
+	assert_eq!(count, 2);
+	
+	assert_eq!(rc_dom.minify_to_string(true), "<li>a<li>b");
+}
+
+#[test]
+fn parse_diagnostics()
+{
+	let (rc_dom, diagnostics) = RcDom::from_bytes_with_diagnostics(b"<!DOCTYPE html>\n<html><body>\n<p>a</b>\n</body></html>");
+	
+	assert!(diagnostics.iter().any(|diagnostic| diagnostic.is_error() && diagnostic.line == Some(3)), "{:?}", diagnostics);
+	
+	match rc_dom.verify_with_diagnostics(Path::new("test.html"), &diagnostics)
+	{
+		Err(HtmlError::ParseErrors(_, errors)) => assert_eq!(errors.len(), diagnostics.len()),
+		
+		_ => panic!("Should have parse errors"),
+	}
+}
+
+#[test]
+fn parse_options()
+{
+	let html: &[u8] = b"<!DOCTYPE html><html><head></head><body><noscript><p>a</p></noscript></body></html>";
+	let scripting_disabled = HtmlParseOptions::default().with_scripting_enabled(false);
+	let noscript_is_markup = |rc_dom: &RcDom| rc_dom.select_first_str("noscript > p").unwrap().is_some();
+	
+	assert!(!noscript_is_markup(&RcDom::from_bytes(html)));
+	assert!(noscript_is_markup(&RcDom::from_bytes_with_options(html, &scripting_disabled)));
+	assert!(noscript_is_markup(&RcDom::from_bytes_with_diagnostics_and_options(html, &scripting_disabled).0));
+	assert!(noscript_is_markup(&RcDom::from_bytes_with_source_locations_and_options(html, &scripting_disabled).0));
+	assert!(noscript_is_markup(&RcDom::from_bytes_sniffing_character_encoding_with_options(html, None, &scripting_disabled).0));
+	assert!(noscript_is_markup(&RcDom::from_reader_with_options(html, "test.html", &scripting_disabled).unwrap()));
+	
+	let fragment = RcDom::from_fragment_bytes_with_options(b"<noscript><p>a</p></noscript>", local_name!("body").qual_name(), &scripting_disabled);
+	assert!(noscript_is_markup(&fragment));
+}
+
+#[test]
+fn sniff_character_encoding()
+{
+	let (rc_dom, sniffed_character_encoding) = RcDom::from_bytes_sniffing_character_encoding(b"<!DOCTYPE html><html><head><meta charset=\"iso-8859-1\"></head><body><p>caf\xE9</p></body></html>", None);
+	
+	assert_eq!(sniffed_character_encoding.encoding, WINDOWS_1252);
+	assert_eq!(sniffed_character_encoding.confidence, CharacterEncodingConfidence::Tentative);
+	assert!(rc_dom.minify_to_string(true).contains("café"));
+	
+	let (_, sniffed_character_encoding) = RcDom::from_bytes_sniffing_character_encoding(b"\xEF\xBB\xBF<meta charset=windows-1252>", None);
+	assert_eq!(sniffed_character_encoding.encoding, UTF_8);
+	assert_eq!(sniffed_character_encoding.confidence, CharacterEncodingConfidence::Certain);
+	
+	let (_, sniffed_character_encoding) = RcDom::from_bytes_sniffing_character_encoding(b"<meta content='text/html; charset=iso-8859-2' http-equiv=content-type charset=utf-8>", None);
+	assert_eq!(sniffed_character_encoding.encoding.name(), "ISO-8859-2");
+}
+
+#[test]
+fn from_reader()
+{
+	let file = ::std::fs::File::open("src/tests.html").unwrap();
+	let rc_dom = RcDom::from_reader_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type(file, "src/tests.html").expect("invalid HTML");
+	
+	let expected = RcDom::from_file_path_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type("src/tests.html").unwrap();
+	assert_eq!(rc_dom.minify_to_string(true), expected.minify_to_string(true));
+}
+
+#[test]
+fn verification_policy()
+{
+	let bytes = b"<html><body><p>no DOCTYPE, so quirks mode</p></body></html>";
+	
+	assert!(RcDom::from_bytes_verified_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type(bytes, "test.html").is_err());
+	
+	let (_, report) = RcDom::from_bytes_verified_with_policy_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type(bytes, "test.html", &VerificationPolicy::lenient()).expect("lenient policy should only warn");
+	assert!(report.warnings.iter().any(|warning| warning.check == VerificationCheck::Quirks), "{:?}", report);
+	
+	let policy = VerificationPolicy::lenient().with_quirks(VerificationAction::Ignore);
+	let (_, report) = RcDom::from_bytes_verified_with_policy_and_stripped_of_comments_and_processing_instructions_and_with_a_sane_doc_type(bytes, "test.html", &policy).unwrap();
+	assert!(report.warnings.iter().all(|warning| warning.check != VerificationCheck::Quirks), "{:?}", report);
+}
+
+#[test]
+fn source_locations()
+{
+	let (rc_dom, source_locations) = RcDom::from_bytes_with_source_locations(b"<!DOCTYPE html>\n<html>\n<body>\n<!-- comment -->\n<p>some\ntext</p>\n</body>\n</html>");
+	
+	let selector = parse_css_selector("p").unwrap();
+	let mut lines = Vec::new();
+	rc_dom.find_all_matching_child_nodes_depth_first_excluding_this_one(&selector, &mut |node|
+	{
+		lines.push(node.source_location(&source_locations).map(|source_location| source_location.line));
+		lines.push(node.first_child().unwrap().source_location(&source_locations).map(|source_location| source_location.line));
+		false
+	});
+	assert_eq!(lines, vec![Some(5), Some(5)]);
+	
+	let doctype = rc_dom.document.first_child().unwrap();
+	assert_eq!(doctype.source_location(&source_locations).unwrap().line, 1);
+	assert_eq!(rc_dom.document.source_location(&source_locations), None);
+}
+
+#[test]
+fn source_locations_of_multi_line_start_tags()
+{
+	let (rc_dom, source_locations) = RcDom::from_bytes_with_source_locations(b"<!DOCTYPE html>\n<html><body>\n  <p\n   class=a>x</p><!-- c\n -->\n</body></html>");
+	
+	let selector = parse_css_selector("p").unwrap();
+	let mut locations = Vec::new();
+	rc_dom.find_all_matching_child_nodes_depth_first_excluding_this_one(&selector, &mut |node|
+	{
+		locations.push(node.source_location(&source_locations).unwrap());
+		locations.push(node.first_child().unwrap().source_location(&source_locations).unwrap());
+		locations.push(node.next_sibling(false).unwrap().source_location(&source_locations).unwrap());
+		false
+	});
+	assert_eq!(locations, vec![SourceLocation { line: 3, column: Some(3) }, SourceLocation { line: 4, column: None }, SourceLocation { line: 4, column: Some(17) }]);
+	
+	let doctype = rc_dom.document.first_child().unwrap();
+	assert_eq!(doctype.source_location(&source_locations).unwrap(), SourceLocation { line: 1, column: Some(1) });
+	let html = doctype.next_sibling(false).unwrap();
+	assert_eq!(html.source_location(&source_locations).unwrap(), SourceLocation { line: 2, column: Some(1) });
+}
+
+#[test]
+fn source_locations_of_elements_without_start_tags()
+{
+	let (rc_dom, source_locations) = RcDom::from_bytes_with_source_locations(b"<!DOCTYPE html><p><b>x</p><p>y <b>z</b></p><p><b>w</b></p><table><tr><td>t</table>");
+	
+	let selector = parse_css_selector("b").unwrap();
+	let mut columns = Vec::new();
+	rc_dom.find_all_matching_child_nodes_depth_first_excluding_this_one(&selector, &mut |node|
+	{
+		columns.push(node.source_location(&source_locations).unwrap().column);
+		false
+	});
+	assert_eq!(columns, vec![Some(19), None, Some(32), None, Some(47)]);
+	
+	let tbody = rc_dom.select_first(&parse_css_selector("tbody").unwrap()).unwrap();
+	assert_eq!(tbody.source_location(&source_locations).unwrap().column, None);
+	assert_eq!(tbody.first_child().unwrap().source_location(&source_locations).unwrap().column, Some(66));
+}
+
+#[test]
+fn content_model_validator()
+{
+	let mut rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><title>t</title></head><body><main><p>text</p></main><ul><li><a href=/>link</a></ul></body></html>");
+	assert_eq!(ContentModelValidator::default().validate(&rc_dom).len(), 0);
+	
+	let mut nodes = Vec::new();
+	for selector in ["p", "a"].iter()
+	{
+		let selector = parse_css_selector(selector).unwrap();
+		rc_dom.find_all_matching_child_nodes_depth_first_excluding_this_one(&selector, &mut |node|
+		{
+			nodes.push(node.clone());
+			false
+		});
+	}
+	nodes[0].append_new_element_to(&mut rc_dom, local_name!("div").qual_name(), vec![]);
+	nodes[1].append_new_element_to(&mut rc_dom, local_name!("button").qual_name(), vec![]);
+	
+	let violations = ContentModelValidator::default().validate(&rc_dom);
+	assert_eq!(violations.len(), 2, "{:?}", violations);
+	assert_eq!(violations[0].path, "html > body > main > p > div");
+	assert_eq!(violations[0].kind, ContentModelViolationKind::ElementNotPermittedInParent { parent: local_name!("p") });
+	assert_eq!(violations[1].kind, ContentModelViolationKind::ElementNotPermittedInAncestor { ancestor: local_name!("a") });
+	
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><title>t</title></head><body><dl><div><a><dt>term</dt></a></div></dl><svg><foreignObject><div><li>x</li></div></foreignObject></svg><my-element><li>y</li></my-element></body></html>");
+	let violations = ContentModelValidator::default().validate(&rc_dom);
+	let paths = violations.iter().map(|violation| &violation.path[..]).collect::<Vec<_>>();
+	assert_eq!(paths, vec!["html > body > svg > foreignObject > div > li", "html > body > my-element > li"]);
+	assert_eq!(violations[0].kind, ContentModelViolationKind::ElementNotPermittedInParent { parent: local_name!("div") });
+	assert_eq!(violations[1].kind, ContentModelViolationKind::ElementNotPermittedInParent { parent: local_name!("body") });
+}
+
+#[test]
+fn amp()
+{
+	use ::amp::*;
+	
+	let mut rc_dom = RcDom::from_bytes("<!DOCTYPE html><html amp><head><meta charset=\"utf-8\"><meta name=\"viewport\" content=\"width=device-width\"><script async src=\"https://cdn.ampproject.org/v0.js\"></script><style amp-boilerplate>body{}</style><noscript><style amp-boilerplate>body{}</style></noscript></head><body><img src=a.png width=10 height=10 alt=\"日本語の画像\"><img src=b.png><video src=https://example.com/c.mp4 width=10 height=10></video></body></html>".as_bytes());
+	
+	let violations = AmpValidator::default().validate(&rc_dom);
+	assert_eq!(violations.iter().filter(|violation| violation.kind == AmpViolationKind::DisallowedElement).count(), 3, "{:?}", violations);
+	
+	let report = AmpTransformer::default().transform(&mut rc_dom);
+	assert_eq!(report.converted.len(), 2);
+	assert_eq!(report.not_converted.len(), 1);
+	
+	let violations = AmpValidator::default().validate(&rc_dom);
+	assert_eq!(violations.len(), 1, "{:?}", violations);
+	assert!(rc_dom.minify_to_string(false).contains("custom-element=amp-video"));
+}
+
+#[test]
+fn accessibility_audit()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><title>t</title></head><body><h1>a</h1><h3>b</h3><img src=a.png><img src=b.png alt=''><label>Name <input name=n></label><input id=e><button role=button aria-pressed=true aria-checked=true>c</button><div role='widget foo'></div></body></html>");
+	
+	let findings = AccessibilityAudit::default().audit(&rc_dom);
+	let rules: Vec<&str> = findings.iter().map(|finding| finding.rule.identifier()).collect();
+	assert_eq!(rules, vec!["html-has-lang", "heading-order", "image-alt", "label", "aria-redundant-role", "aria-allowed-attr", "aria-abstract-role", "aria-valid-role"], "{:?}", findings);
+}
+
+#[test]
+fn aria_role()
+{
+	assert_eq!("Switch".parse::<AriaRole>(), Ok(AriaRole::switch));
+	assert!("foo".parse::<AriaRole>().is_err());
+	assert!(AriaRole::widget.is_abstract());
+	assert_eq!(AriaRole::main.category(), AriaRoleCategory::Landmark);
+	assert!(AriaRole::switch.is_a(AriaRole::input));
+	assert!(!AriaRole::switch.is_a(AriaRole::landmark));
+	assert!(AriaRole::switch.supported_states_and_properties().contains(&"aria-checked"));
+	assert!(AriaRole::treegrid.supported_states_and_properties().contains(&"aria-rowcount"));
+	assert!(!AriaRole::generic.supports_state_or_property("aria-label"));
+	
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><body><header>a</header><article><header>b</header></article><section>c</section><section aria-label=d>d</section><input type=search><table role=grid><tr><td>e</td></tr></table><main><aside>f</aside></main><article><aside>g</aside></article></body></html>");
+	let roles: Vec<Option<AriaRole>> = ["body > header", "article > header", "section", "section[aria-label]", "input", "td", "main > aside", "article > aside"].iter().map(|selector|
+	{
+		let mut role = None;
+		rc_dom.find_all_matching_child_nodes_depth_first_including_this_one(&parse_css_selector(selector).unwrap(), &mut |node|
+		{
+			role = implicit_role(node);
+			true
+		});
+		role
+	}).collect();
+	assert_eq!(roles, vec![Some(AriaRole::banner), Some(AriaRole::generic), Some(AriaRole::generic), Some(AriaRole::region), Some(AriaRole::searchbox), Some(AriaRole::gridcell), Some(AriaRole::complementary), Some(AriaRole::generic)]);
+}
+
+#[test]
+fn accessible_name()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><body><span id=a>Billing</span><span id=b hidden>Name</span><input id=c aria-labelledby='a b'><label for=d>Email</label><input id=d title='Your email'><button>Save <span aria-hidden=true>!</span><img src=x alt=changes></button><img id=e src=y alt='' title=Logo><label>Flavour <select><option>Lemon<option selected>Lime</select></label><a href=f aria-describedby=a>More</a></body></html>");
+	let node = |selector: &str|
+	{
+		let mut found = None;
+		rc_dom.find_all_matching_child_nodes_depth_first_including_this_one(&parse_css_selector(selector).unwrap(), &mut |node|
+		{
+			found = Some(node.clone());
+			true
+		});
+		found.unwrap()
+	};
+	
+	assert_eq!(node("#c").accessible_name(), "Billing Name");
+	assert_eq!(node("#d").accessible_name(), "Email");
+	assert_eq!(node("#d").accessible_description(), "Your email");
+	assert_eq!(node("button").accessible_name(), "Save changes");
+	assert_eq!(node("#e").accessible_name(), "Logo");
+	assert_eq!(node("select").accessible_name(), "Flavour");
+	assert_eq!(node("a").accessible_name(), "More");
+	assert_eq!(node("a").accessible_description(), "Billing");
+}
+
+#[test]
+fn non_tree_structural_pseudo_classes()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html lang=fr><body><form><fieldset disabled><legend><input id=a></legend><input id=b required placeholder=x></fieldset><input id=c type=checkbox checked><input id=d type=number min=1 max=5 value=9 readonly><input id=e type=number min=1 max=5 value=9><select id=f><option>a<option>b</select><button id=g>Go</button></form><p id=h dir=rtl><span id=i>x</span></p><a id=j href=k>l</a></body></html>");
+	let matching = |selector: &str|
+	{
+		let mut identifiers = Vec::new();
+		rc_dom.find_all_matching_child_nodes_depth_first_including_this_one(&parse_css_selector(selector).unwrap(), &mut |node|
+		{
+			identifiers.push(node.get_attribute("id").unwrap_or_default());
+			false
+		});
+		identifiers.join(",")
+	};
+	
+	assert_eq!(matching("input:disabled"), "b");
+	assert_eq!(matching("input:enabled"), "a,c,d,e");
+	assert_eq!(matching(":required"), "b");
+	assert_eq!(matching("input:placeholder-shown"), "b");
+	assert_eq!(matching("input:checked"), "c");
+	assert_eq!(matching(":default"), "c,g");
+	assert_eq!(matching(":out-of-range"), "e");
+	assert_eq!(matching("input:read-only"), "b,c,d");
+	assert_eq!(matching("span:lang(fr)"), "i");
+	assert_eq!(matching("span:dir(rtl)"), "i");
+	assert_eq!(matching("form:invalid"), "");
+	assert_eq!(matching("a:link"), "j");
+	assert_eq!(matching("a:visited"), "j");
+	
+	let hovered = |node: &Rc<Node>, dynamic_pseudo_class: DynamicPseudoClass| dynamic_pseudo_class == DynamicPseudoClass::Hover && node.is_only_local(&local_name!("p"));
+	let selector = parse_css_selector(":hover").unwrap();
+	let p = rc_dom.document.children.borrow()[1].children.borrow()[1].children.borrow()[1].clone();
+	assert!(p.matches_with_dynamic_pseudo_class_policy(&selector, &DynamicPseudoClassPolicy::callback(hovered)));
+	assert!(!p.matches_with_dynamic_pseudo_class_policy(&selector, &DynamicPseudoClassPolicy::NeverMatch));
+	
+	let a = rc_dom.select_first_str("a").unwrap().unwrap();
+	assert!(a.matches_with_dynamic_pseudo_class_policy(&parse_css_selector(":link").unwrap(), &DynamicPseudoClassPolicy::NeverMatch));
+	assert!(!a.matches_with_dynamic_pseudo_class_policy(&parse_css_selector(":visited").unwrap(), &DynamicPseudoClassPolicy::NeverMatch));
+}
+
+#[test]
+fn select()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><body><p id=a><span id=b></span></p><p id=c></p></body></html>");
+	let identifier = |node: Rc<Node>| node.get_attribute("id").unwrap();
+	
+	let identifiers: Vec<String> = rc_dom.select_all_str("[id]").unwrap().map(identifier).collect();
+	assert_eq!(identifiers, vec!["a", "b", "c"]);
+	assert_eq!(rc_dom.select_first_str("p").unwrap().map(identifier), Some("a".to_owned()));
+	assert!(rc_dom.select_first_str("p:nonsense(").is_err());
+	
+	let paragraph = rc_dom.select_first(&parse_css_selector("#a").unwrap()).unwrap();
+	assert!(paragraph.select_first_str("p").unwrap().is_none());
+	assert_eq!(paragraph.select_all_str("span").unwrap().count(), 1);
+}
+
+#[test]
+fn closest_and_matches_within()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><body><form id=a><div data-widget=b><p><input id=c></p></div></form><div id=d><p id=e></p></div></body></html>");
+	let input = rc_dom.select_first_str("#c").unwrap().unwrap();
+	
+	assert_eq!(input.ancestors().count(), 6);
+	assert!(Rc::ptr_eq(&input.closest(&parse_css_selector("[data-widget]").unwrap()).unwrap(), &rc_dom.select_first_str("[data-widget]").unwrap().unwrap()));
+	assert!(Rc::ptr_eq(&input.closest(&parse_css_selector("input").unwrap()).unwrap(), &input));
+	assert!(input.closest(&parse_css_selector("section").unwrap()).is_none());
+	
+	let scope_root = rc_dom.select_first_str("#d").unwrap().unwrap();
+	let paragraph = rc_dom.select_first_str("#e").unwrap().unwrap();
+	let selector = parse_scoped_css_selector(":scope > p").unwrap();
+	assert!(paragraph.matches_within(&selector, &scope_root));
+	assert!(!input.parent().unwrap().matches_within(&selector, &scope_root));
+	assert!(!paragraph.matches_within(&selector, &input));
+	assert!(parse_scoped_css_selector("[title=':scope']").is_ok());
+	assert!(parse_scoped_css_selector("p:scoped").is_err());
+}
+
+#[test]
+fn selector_set()
+{
+	let first = RcDom::from_bytes(b"<!DOCTYPE html><html><body><nav class='menu main'><a id=home href=/>Home</a></nav><p class=intro>Hi</p></body></html>");
+	let second = RcDom::from_bytes(b"<!DOCTYPE html><html><body><article><p>Text</p></article></body></html>");
+	
+	let mut selector_set = SelectorSet::default();
+	let nav_link = selector_set.insert(parse_css_selector("nav.menu a").unwrap());
+	let home = selector_set.insert(parse_css_selector("body #home").unwrap());
+	let intro = selector_set.insert(parse_css_selector("article .intro").unwrap());
+	let paragraph = selector_set.insert(parse_css_selector("p").unwrap());
+	let universal = selector_set.insert(parse_css_selector("*").unwrap());
+	let table = selector_set.insert(parse_css_selector("table td").unwrap());
+	assert_eq!(selector_set.len(), 6);
+	
+	let selector_set_matches = selector_set.matches(&[first, second]);
+	assert_eq!(selector_set_matches.nodes(nav_link).len(), 1);
+	assert!(selector_set_matches.is_matched(home));
+	assert!(!selector_set_matches.is_matched(intro));
+	assert_eq!(selector_set_matches.nodes(paragraph).len(), 2);
+	assert_eq!(selector_set_matches.nodes(universal).len(), 11);
+	assert_eq!(selector_set_matches.unmatched(), vec![intro, table]);
+	
+	let svg = RcDom::from_bytes(b"<!DOCTYPE html><html><body><svg><defs><linearGradient id=g></linearGradient></defs><clipPath></clipPath></svg></body></html>");
+	let mut selector_set = SelectorSet::default();
+	let linear_gradient = selector_set.insert(parse_css_selector("defs linearGradient").unwrap());
+	let clip_path = selector_set.insert(parse_css_selector("svg clipPath").unwrap());
+	let selector_set_matches = selector_set.matches(&[svg]);
+	assert!(selector_set_matches.is_matched(linear_gradient));
+	assert!(selector_set_matches.is_matched(clip_path));
+}
+
+#[test]
+fn purify()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><style>.unused { color: red } p { color: blue }</style></head><body><p>Hello</p></body></html>");
+	let stylesheet = Stylesheet::parse("@font-face { font-family: Used; src: url(used.woff) } @font-face { font-family: 'Unused'; src: url(unused.woff) } @keyframes spin { from { opacity: 0 } to { opacity: 1 } } @keyframes fade { from { opacity: 0 } to { opacity: 1 } } p, .missing { font: bold 12px Used, serif; animation: spin 1s } .missing { animation-name: fade } @media print { .missing { color: blue } } @media screen { p::before { content: 'x' } } .js-open { display: block }").ok().unwrap();
+	let safelist = PurifySafelist::default().with_pattern("js-*");
+	let css = purify_stylesheet(stylesheet, from_ref(&rc_dom), &safelist).to_css_string(false);
+	assert!(css.contains("font-family:Used"));
+	assert!(!css.contains("Unused"));
+	assert!(css.contains("@keyframes spin"));
+	assert!(!css.contains("fade"));
+	assert!(css.contains("p{"));
+	assert!(!css.contains(".missing"));
+	assert!(!css.contains("@media print"));
+	assert!(css.contains("p::before"));
+	assert!(css.contains(".js-open"));
+	
+	let links = RcDom::from_bytes(b"<!DOCTYPE html><html><body><a href=/>x</a></body></html>");
+	let css = purify_stylesheet(Stylesheet::parse("a:link { color: red } a:visited { color: blue }").ok().unwrap(), from_ref(&links), &PurifySafelist::default()).to_css_string(false);
+	assert!(css.contains("a:link"));
+	assert!(css.contains("a:visited"));
+	
+	let mut rc_dom = rc_dom;
+	rc_dom.purify_style_elements(Path::new("test.html"), &PurifySafelist::default()).unwrap();
+	let style = rc_dom.select_first_str("style").unwrap().unwrap();
+	let css = match style.children.borrow()[0].data
+	{
+		NodeData::Text { ref contents } => contents.borrow().to_string(),
+		_ => unreachable!(),
+	};
+	assert!(css.starts_with("p{"));
+	assert!(!css.contains(".unused"));
+	
+	let mut rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><style>@font-face { font-family: Shared; src: url(shared.woff) } @keyframes pulse { from { opacity: 0 } to { opacity: 1 } } @keyframes spin { from { opacity: 0 } to { opacity: 1 } }</style><style>p { font-family: Shared }</style></head><body><p style='animation: pulse 1s'>Hello</p></body></html>");
+	rc_dom.purify_style_elements(Path::new("test.html"), &PurifySafelist::default()).unwrap();
+	let style = rc_dom.select_first_str("style").unwrap().unwrap();
+	let css = style.text_content();
+	assert!(css.contains("font-family:Shared"));
+	assert!(css.contains("@keyframes pulse"));
+	assert!(!css.contains("spin"));
+}
+
+#[test]
+fn inline_style_elements()
+{
+	let mut rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><style>p { color: red; margin: 0 } .note { color: blue !important } #first { color: green; padding: 1px } a:hover, a { text-decoration: none } @media (max-width: 600px) { p { margin: 1px } }</style><style>p { font-size: 12px }</style></head><body><p id=first class=note style='padding: 2px'>Hi <a href=/>there</a></p><p>Bye</p></body></html>");
+	rc_dom.inline_style_elements(Path::new("test.html")).unwrap();
+	
+	let style = |selector: &str| rc_dom.select_first_str(selector).unwrap().unwrap().get_attribute("style").unwrap();
+	assert_eq!(style("#first"), "margin:0;font-size:12px;padding:2px;color:blue!important");
+	assert_eq!(style("p:last-child"), "color:red;margin:0;font-size:12px");
+	assert_eq!(style("a"), "text-decoration:none");
+	
+	assert_eq!(rc_dom.select_all_str("style").unwrap().count(), 1);
+	let residual = rc_dom.select_first_str("style").unwrap().unwrap();
+	let css = match residual.children.borrow()[0].data
+	{
+		NodeData::Text { ref contents } => contents.borrow().to_string(),
+		_ => unreachable!(),
+	};
+	assert_eq!(css, "a:hover{text-decoration: none }@media (max-width:600px){p{margin: 1px }}");
+}
+
+#[test]
+fn cascade_resolver()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><style>body { color: black; margin: 0 } p { color: red; border: 1px solid } #intro { color: blue } .note { margin: inherit; color: green !important }</style></head><body><p id=intro class=note style='color: purple; padding: 2px'>Hi</p></body></html>");
+	let external_stylesheet = Stylesheet::parse("p { font-size: 12px; color: orange; margin-top: 5px }").ok().unwrap();
+	let cascade_resolver = CascadeResolver::new(&rc_dom, vec![external_stylesheet], Path::new("test.html")).unwrap();
+	let paragraph = rc_dom.select_first_str("p").unwrap().unwrap();
+	
+	let matched_rules = cascade_resolver.matched_rules(&paragraph);
+	let origins: Vec<CascadeOrigin> = matched_rules.iter().map(|matched_rule| matched_rule.origin).collect();
+	assert_eq!(origins, vec![CascadeOrigin::ExternalStylesheet(0), CascadeOrigin::StyleElement(0), CascadeOrigin::StyleElement(0), CascadeOrigin::StyleElement(0), CascadeOrigin::StyleAttribute]);
+	assert!(matched_rules[3].specificity > matched_rules[2].specificity);
+	let overridden: Vec<(String, bool)> = matched_rules.iter().flat_map(|matched_rule| matched_rule.declarations.iter()).map(|matched_declaration| (matched_declaration.declaration.name.to_string(), matched_declaration.overridden)).collect();
+	assert_eq!(overridden, vec![("font-size".to_owned(), false), ("color".to_owned(), true), ("margin-top".to_owned(), true), ("color".to_owned(), true), ("border".to_owned(), false), ("margin".to_owned(), false), ("color".to_owned(), false), ("color".to_owned(), true), ("color".to_owned(), true), ("padding".to_owned(), false)]);
+	
+	let computed_values = cascade_resolver.computed_values(&paragraph);
+	let computed_value = |name: &str| computed_values.get(name).map(|computed_value| (computed_value.value.as_str(), computed_value.inherited));
+	assert_eq!(computed_value("color"), Some(("green", false)));
+	assert_eq!(computed_value("margin"), Some(("0", true)));
+	assert_eq!(computed_value("padding"), Some(("2px", false)));
+	assert_eq!(computed_value("font-size"), Some(("12px", false)));
+	assert_eq!(computed_value("margin-top"), None);
+	assert_eq!(computed_values.len(), 5);
+	
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><body style='font-size: 20px'><p style='font: 12px serif'>Hi</p></body></html>");
+	let cascade_resolver = CascadeResolver::new(&rc_dom, vec![], Path::new("test.html")).unwrap();
+	let computed_values = cascade_resolver.computed_values(&rc_dom.select_first_str("p").unwrap().unwrap());
+	assert_eq!(computed_values.get("font").map(|computed_value| computed_value.value.as_str()), Some("12px serif"));
+	assert_eq!(computed_values.get("font-size"), None);
+}
+
+#[test]
+fn attributes()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><body><a href=/ Title=Home>Home</a><svg><use xlink:href=#icon viewBox='0 0 1 1'></use></svg></body></html>");
+	let link = rc_dom.select_first_str("a").unwrap().unwrap();
+	
+	assert_eq!(link.get_attribute("HREF"), Some("/".to_owned()));
+	assert!(link.has_attribute("title"));
+	assert!(!link.has_attribute("rel"));
+	link.set_attribute("Rel", "nofollow");
+	link.set_attribute("href", "/home");
+	assert_eq!(link.remove_attribute("title"), Some("Home".to_owned()));
+	let names: Vec<(String, String)> = link.attributes().map(|attribute| (attribute.name.local.to_string(), attribute.value.to_string())).collect();
+	assert_eq!(names, vec![("href".to_owned(), "/home".to_owned()), ("rel".to_owned(), "nofollow".to_owned())]);
+	
+	let use_ = rc_dom.select_first_str("use").unwrap().unwrap();
+	assert_eq!(use_.get_attribute("xlink:href"), Some("#icon".to_owned()));
+	assert_eq!(use_.get_attribute("viewBox"), Some("0 0 1 1".to_owned()));
+	assert_eq!(use_.get_attribute_ns(&ns!(xlink), &local_name!("href")), Some("#icon".to_owned()));
+	use_.set_attribute_ns(QualName::new(Some(namespace_prefix!("xlink")), ns!(xlink), local_name!("href")), "#other");
+	assert_eq!(use_.remove_attribute_ns(&ns!(xlink), &local_name!("href")), Some("#other".to_owned()));
+	assert!(!use_.has_attribute_ns(&ns!(xlink), &local_name!("href")));
+	assert!(rc_dom.document.get_attribute("href").is_none());
+}
+
+#[test]
+fn class_list()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><body><p class='  b a\tb '>Hi</p><p>Bye</p></body></html>");
+	let paragraph = rc_dom.select_first_str("p").unwrap().unwrap();
+	let class_list = paragraph.class_list();
+	
+	assert_eq!(class_list.iter().collect::<Vec<_>>(), vec!["b", "a"]);
+	assert!(class_list.contains("a"));
+	assert!(!class_list.contains("c"));
+	assert!(!class_list.contains("a b"));
+	assert!(!class_list.contains(""));
+	class_list.add("c").unwrap();
+	assert_eq!(paragraph.get_attribute("class"), Some("b a c".to_owned()));
+	class_list.remove("b").unwrap();
+	assert_eq!(class_list.toggle("a"), Ok(false));
+	assert_eq!(class_list.toggle("d"), Ok(true));
+	assert_eq!(class_list.replace("d", "c"), Ok(true));
+	assert_eq!(class_list.replace("x", "y"), Ok(false));
+	assert_eq!(class_list.add("x y"), Err(InvalidClass { class: "x y".to_owned() }));
+	assert!(class_list.replace("c", "").is_err());
+	assert_eq!(paragraph.get_attribute("class"), Some("c".to_owned()));
+	
+	let other = rc_dom.select_first_str("p:last-child").unwrap().unwrap();
+	other.class_list().remove("a").unwrap();
+	assert!(!other.has_attribute("class"));
+	other.class_list().add("a").unwrap();
+	assert!(other.matches(&parse_css_selector(".a").unwrap()));
+}
+
+#[test]
+fn traversal()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head></head><body><ul id=list>\n\t<li id=a>A</li>\n\t<!-- b --><li id=b>B</li>\n\t<li id=c>C</li>\n</ul></body></html>");
+	let describe = |node: Rc<Node>| match node.data
+	{
+		NodeData::Element { ref name, .. } => name.local.to_string(),
+		NodeData::Text { ref contents } => format!("{:?}", contents.borrow().to_string()),
+		NodeData::Comment { .. } => "comment".to_owned(),
+		_ => "other".to_owned(),
+	};
+	let list = rc_dom.select_first_str("#list").unwrap().unwrap();
+	let b = rc_dom.select_first_str("#b").unwrap().unwrap();
+	
+	assert_eq!(list.descendants(true).map(describe).collect::<Vec<_>>(), vec!["li", "\"A\"", "li", "\"B\"", "li", "\"C\""]);
+	assert_eq!(list.descendants(false).count(), 11);
+	assert_eq!(list.descendants_post_order(true).map(describe).collect::<Vec<_>>(), vec!["\"A\"", "li", "\"B\"", "li", "\"C\"", "li"]);
+	assert_eq!(list.children_elements().count(), 3);
+	assert_eq!(b.following_siblings(true).map(describe).collect::<Vec<_>>(), vec!["li"]);
+	assert_eq!(b.following_siblings(false).count(), 3);
+	assert_eq!(b.preceding_siblings(true).map(describe).collect::<Vec<_>>(), vec!["li"]);
+	assert_eq!(b.preceding_siblings(false).map(describe).collect::<Vec<_>>(), vec!["comment", "\"\\n\\t\"", "li", "\"\\n\\t\""]);
+	
+	let edges: Vec<String> = b.traverse(false).map(|edge| match edge
+	{
+		Edge::Open(node) => format!("+{}", describe(node)),
+		Edge::Close(node) => format!("-{}", describe(node)),
+	}).collect();
+	assert_eq!(edges, vec!["+li", "+\"B\"", "-\"B\"", "-li"]);
+	assert_eq!(rc_dom.document.traverse(true).count(), 2 * rc_dom.document.descendants(true).count() + 2);
+}
+
+#[test]
+fn text_content_and_inner_text()
+{
+	let rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head><title>T</title><style>p { color: red }</style></head><body><div id=root>\n  <h1>Hello,   <em>world</em></h1>\n  <p>One<br>Two</p><p hidden>Hidden</p><p style='display: none'>None</p>\n  <pre>a\n  b</pre><script>var x;</script><table><tr><td>1</td><td>2</td></tr></table>\n</div></body></html>");
+	let root = rc_dom.select_first_str("#root").unwrap().unwrap();
+	
+	assert_eq!(rc_dom.select_first_str("h1").unwrap().unwrap().text_content(), "Hello,   world");
+	assert!(root.text_content().contains("var x;"));
+	assert_eq!(root.inner_text(), "Hello, world\n\nOne\nTwo\n\na\n  b\n1\t2");
+	assert_eq!(rc_dom.select_first_str("p[hidden]").unwrap().unwrap().inner_text(), "Hidden");
+}
+
+#[test]
+fn inner_html_and_outer_html()
+{
+	let mut rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head></head><body><div id=root class=\"a\">\n  <p>One &amp; <b>two</b></p>\n</div><table><tbody id=rows><tr><td>1</td></tr></tbody></table></body></html>");
+	let root = rc_dom.select_first_str("#root").unwrap().unwrap();
+	
+	assert_eq!(root.inner_html(false), "\n  <p>One &amp; <b>two</b></p>\n");
+	assert_eq!(root.outer_html(false), "<div id=\"root\" class=\"a\">\n  <p>One &amp; <b>two</b></p>\n</div>");
+	assert_eq!(root.inner_html(true), "<p>One &amp; <b>two</b></p>");
+	assert_eq!(root.outer_html(true), "<div id=root class=a><p>One &amp; <b>two</b></p></div>");
+	
+	let inserted = root.set_inner_html(&mut rc_dom, "<span>x</span>y").unwrap();
+	assert_eq!(inserted.len(), 2);
+	assert_eq!(root.inner_html(false), "<span>x</span>y");
+	assert!(Rc::ptr_eq(&inserted[0].parent().unwrap(), &root));
+	
+	let rows = rc_dom.select_first_str("#rows").unwrap().unwrap();
+	let inserted = rows.set_inner_html(&mut rc_dom, "<tr><td>2</td></tr><tr><td>3</td></tr>").unwrap();
+	assert_eq!(inserted.len(), 2);
+	assert_eq!(rows.inner_html(false), "<tr><td>2</td></tr><tr><td>3</td></tr>");
+	
+	let span = rc_dom.select_first_str("span").unwrap().unwrap();
+	let inserted = span.replace_with_html(&mut rc_dom, "<i>a</i><i>b</i>").unwrap();
+	assert_eq!(inserted.len(), 2);
+	assert!(span.parent().is_none());
+	assert_eq!(root.inner_html(false), "<i>a</i><i>b</i>y");
+	
+	let document = rc_dom.document.clone();
+	assert!(document.set_inner_html(&mut rc_dom, "<p>z</p>").is_none());
+	let html = rc_dom.select_first_str("html").unwrap().unwrap();
+	assert!(html.replace_with_html(&mut rc_dom, "<p>z</p>").is_none());
+	assert!(html.parent().is_some());
+}
+
+#[test]
+fn deep_clone_and_to_unattached_node()
+{
+	let mut rc_dom = RcDom::from_bytes(b"<!DOCTYPE html><html><head></head><body><ul id=list><li class=item>One<!-- c --><b>1</b></li></ul><template id=t><p>T</p></template></body></html>");
+	let list = rc_dom.select_first_str("#list").unwrap().unwrap();
+	let item = rc_dom.select_first_str(".item").unwrap().unwrap();
+	
+	let clone = item.deep_clone();
+	assert!(clone.parent().is_none());
+	assert_eq!(clone.outer_html(false), item.outer_html(false));
+	let b = clone.children.borrow()[2].clone();
+	assert!(Rc::ptr_eq(&b.parent().unwrap(), &clone));
+	clone.set_attribute("class", "copy");
+	assert_eq!(item.get_attribute("class"), Some("item".to_owned()));
+	
+	let template = rc_dom.select_first_str("#t").unwrap().unwrap();
+	match (&template.data, &template.deep_clone().data)
+	{
+		(&NodeData::Element { template_contents: Some(ref original), .. }, &NodeData::Element { template_contents: Some(ref copy), .. }) =>
+		{
+			assert!(!Rc::ptr_eq(original, copy));
+			assert_eq!(copy.inner_html(false), "<p>T</p>");
+		}
+		_ => panic!("template contents were not cloned"),
+	}
+	
+	let unattached = item.to_unattached_node().unwrap();
+	assert_eq!(unattached.children.len(), 2);
+	list.attach_child(&mut rc_dom, unattached);
+	assert_eq!(list.inner_html(false), "<li class=\"item\">One<!-- c --><b>1</b></li><li class=\"item\">One<b>1</b></li>");
+	assert!(rc_dom.document.children.borrow()[0].to_unattached_node().is_none());
+}